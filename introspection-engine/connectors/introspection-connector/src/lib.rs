@@ -61,6 +61,9 @@ pub struct Warning {
 pub struct IntrospectionResultOutput {
     /// Datamodel
     pub datamodel: String,
+    /// The introspected datamodel, serialized as a JSON AST for tooling that wants a
+    /// machine-readable representation instead of parsing the rendered `datamodel` string.
+    pub datamodel_json: Value,
     /// warnings
     pub warnings: Vec<Warning>,
     /// version
@@ -69,12 +69,39 @@ pub fn calculate_datamodel(
     // commenting out models, fields, enums, enum values
     warnings.append(&mut commenting_out_guardrails(&mut datamodel, &ctx));
 
+    // CHECK constraints are not representable in the Prisma schema, so we can only warn about them
+    let models_with_check_constraints = models_with_check_constraints(schema, &datamodel, ctx.sql_family());
+    if !models_with_check_constraints.is_empty() {
+        warnings.push(crate::warnings::warning_check_constraints(&models_with_check_constraints));
+    }
+
+    // EXCLUDE constraints are not representable in the Prisma schema either
+    let models_with_exclusion_constraints = models_with_exclusion_constraints(schema, &datamodel, ctx.sql_family());
+    if !models_with_exclusion_constraints.is_empty() {
+        warnings.push(crate::warnings::warning_exclusion_constraints(
+            &models_with_exclusion_constraints,
+        ));
+    }
+
+    // serial-backed columns whose sequence has a non-default increment or start can't be fully
+    // represented by `@default(autoincrement())`, so we can only warn about the discrepancy
+    let fields_with_non_default_sequences = fields_with_non_default_sequences(schema, &datamodel, ctx.sql_family());
+    if !fields_with_non_default_sequences.is_empty() {
+        warnings.push(crate::warnings::warning_non_default_sequence(
+            &fields_with_non_default_sequences,
+        ));
+    }
+
     // try to identify whether the schema was created by a previous Prisma version
     let version = version_check.version(&warnings, &datamodel);
 
     // if based on a previous Prisma version add id default opinionations
     add_prisma_1_id_defaults(&version, &mut datamodel, schema, &mut warnings, &ctx);
 
+    // the order in which the checks above run does not match warning code order; sort so the
+    // output is stable regardless of which combination of warnings was produced
+    crate::warnings::sort_warnings(&mut warnings);
+
     debug!("Done calculating datamodel.");
     Ok(IntrospectionResult {
         data_model: datamodel,
@@ -1,12 +1,38 @@
+use crate::introspection_helpers::{
+    DOMAIN_COLUMN_DOCUMENTATION, GENERATED_COLUMN_DOCUMENTATION, HSTORE_COLUMN_DOCUMENTATION,
+};
 use crate::warnings::{
-    warning_enum_values_with_empty_names, warning_fields_with_empty_names, warning_models_without_columns,
-    warning_models_without_identifier, warning_unsupported_types, EnumAndValue, Model, ModelAndField,
-    ModelAndFieldAndType,
+    warning_domain_types, warning_enum_values_with_empty_names, warning_fields_with_empty_names,
+    warning_generated_columns, warning_hstore_types, warning_models_without_columns,
+    warning_models_without_identifier, warning_multi_dimensional_arrays, warning_range_types, warning_spatial_types,
+    warning_unsupported_types, EnumAndValue, Model, ModelAndField, ModelAndFieldAndType,
 };
 use crate::SqlFamilyTrait;
 use datamodel::dml::{Datamodel, FieldType};
 use introspection_connector::{IntrospectionContext, Warning};
 
+// MySQL spatial column types, as produced by `sql-schema-describer`'s MySQL describer.
+const SPATIAL_TYPES: &[&str] = &[
+    "geometry",
+    "point",
+    "linestring",
+    "polygon",
+    "multipoint",
+    "multilinestring",
+    "multipolygon",
+    "geometrycollection",
+];
+
+// Postgres range types, as produced by `sql-schema-describer`'s Postgres describer (`udt_name`).
+const RANGE_TYPES: &[&str] = &[
+    "int4range",
+    "int8range",
+    "numrange",
+    "tsrange",
+    "tstzrange",
+    "daterange",
+];
+
 pub fn commenting_out_guardrails(datamodel: &mut Datamodel, ctx: &IntrospectionContext) -> Vec<Warning> {
     let mut warnings = vec![];
 
@@ -15,7 +41,13 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, ctx: &IntrospectionC
     let models_without_identifiers = models_wihtout_uniques(datamodel, &models_without_columns);
     let fields_with_empty_names = fields_with_empty_names(datamodel);
     let enum_values_with_empty_names = empty_enum_values(datamodel);
-    let unsupported_types = unsupported_types(datamodel);
+    let spatial_types = spatial_types(datamodel);
+    let multi_dimensional_arrays = multi_dimensional_arrays(datamodel);
+    let range_types = range_types(datamodel);
+    let unsupported_types = unsupported_types(datamodel, &spatial_types, &multi_dimensional_arrays, &range_types);
+    let generated_columns = generated_columns(datamodel);
+    let domain_types = domain_types(datamodel);
+    let hstore_types = hstore_types(datamodel);
 
     if !models_without_columns.is_empty() {
         warnings.push(warning_models_without_columns(&models_without_columns))
@@ -33,10 +65,34 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, ctx: &IntrospectionC
         warnings.push(warning_unsupported_types(&unsupported_types))
     }
 
+    if !spatial_types.is_empty() {
+        warnings.push(warning_spatial_types(&spatial_types))
+    }
+
+    if !multi_dimensional_arrays.is_empty() {
+        warnings.push(warning_multi_dimensional_arrays(&multi_dimensional_arrays))
+    }
+
+    if !range_types.is_empty() {
+        warnings.push(warning_range_types(&range_types))
+    }
+
     if !enum_values_with_empty_names.is_empty() {
         warnings.push(warning_enum_values_with_empty_names(&enum_values_with_empty_names))
     }
 
+    if !generated_columns.is_empty() {
+        warnings.push(warning_generated_columns(&generated_columns))
+    }
+
+    if !domain_types.is_empty() {
+        warnings.push(warning_domain_types(&domain_types))
+    }
+
+    if !hstore_types.is_empty() {
+        warnings.push(warning_hstore_types(&hstore_types))
+    }
+
     warnings
 }
 
@@ -149,8 +205,64 @@ fn empty_enum_values(datamodel: &mut Datamodel) -> Vec<EnumAndValue> {
     enum_values_with_empty_names
 }
 
-// fields with unsupported as datatype
-fn unsupported_types(datamodel: &mut Datamodel) -> Vec<ModelAndFieldAndType> {
+// fields backed by a database-generated/computed column
+fn generated_columns(datamodel: &mut Datamodel) -> Vec<ModelAndField> {
+    let mut generated_columns = vec![];
+
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+
+        for field in model.scalar_fields_mut() {
+            if field.documentation.as_deref() == Some(GENERATED_COLUMN_DOCUMENTATION) {
+                generated_columns.push(ModelAndField::new(&model_name, &field.name))
+            }
+        }
+    }
+
+    generated_columns
+}
+
+// fields backed by a Postgres domain type, resolved to its base type
+fn domain_types(datamodel: &mut Datamodel) -> Vec<ModelAndField> {
+    let mut domain_types = vec![];
+
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+
+        for field in model.scalar_fields_mut() {
+            if field.documentation.as_deref() == Some(DOMAIN_COLUMN_DOCUMENTATION) {
+                domain_types.push(ModelAndField::new(&model_name, &field.name))
+            }
+        }
+    }
+
+    domain_types
+}
+
+// fields backed by a Postgres hstore column, resolved to Json
+fn hstore_types(datamodel: &mut Datamodel) -> Vec<ModelAndField> {
+    let mut hstore_types = vec![];
+
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+
+        for field in model.scalar_fields_mut() {
+            if field.documentation.as_deref() == Some(HSTORE_COLUMN_DOCUMENTATION) {
+                hstore_types.push(ModelAndField::new(&model_name, &field.name))
+            }
+        }
+    }
+
+    hstore_types
+}
+
+// fields with unsupported as datatype, except the ones we already give a more specific warning for
+fn unsupported_types(
+    datamodel: &mut Datamodel,
+    spatial_types: &[ModelAndFieldAndType],
+    multi_dimensional_arrays: &[ModelAndFieldAndType],
+    range_types: &[ModelAndFieldAndType],
+) -> Vec<ModelAndFieldAndType> {
     let mut unsupported_types = vec![];
 
     for model in datamodel.models_mut() {
@@ -162,6 +274,27 @@ fn unsupported_types(datamodel: &mut Datamodel) -> Vec<ModelAndFieldAndType> {
                 _ => continue,
             };
 
+            if spatial_types
+                .iter()
+                .any(|spatial| spatial.model == model_name && spatial.field == field.name)
+            {
+                continue;
+            }
+
+            if multi_dimensional_arrays
+                .iter()
+                .any(|array| array.model == model_name && array.field == field.name)
+            {
+                continue;
+            }
+
+            if range_types
+                .iter()
+                .any(|range| range.model == model_name && range.field == field.name)
+            {
+                continue;
+            }
+
             unsupported_types.push(ModelAndFieldAndType {
                 model: model_name.clone(),
                 field: field.name.clone(),
@@ -172,3 +305,90 @@ fn unsupported_types(datamodel: &mut Datamodel) -> Vec<ModelAndFieldAndType> {
 
     unsupported_types
 }
+
+// MySQL spatial columns, which are mapped to `Unsupported` but get their own, more specific warning
+fn spatial_types(datamodel: &mut Datamodel) -> Vec<ModelAndFieldAndType> {
+    let mut spatial_types = vec![];
+
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+
+        for field in model.scalar_fields_mut() {
+            let r#type = match &field.field_type {
+                FieldType::Unsupported(r#type) => r#type,
+                _ => continue,
+            };
+
+            if !SPATIAL_TYPES.contains(&r#type.as_str()) {
+                continue;
+            }
+
+            spatial_types.push(ModelAndFieldAndType {
+                model: model_name.clone(),
+                field: field.name.clone(),
+                tpe: r#type.clone(),
+            })
+        }
+    }
+
+    spatial_types
+}
+
+// Postgres columns declared with more than one array dimension (e.g. `int[][]`), which
+// `sql-schema-describer` maps to `Unsupported` because Prisma only supports one-dimensional
+// arrays. Marked by the `[]` suffix the describer appends to the underlying type name.
+fn multi_dimensional_arrays(datamodel: &mut Datamodel) -> Vec<ModelAndFieldAndType> {
+    let mut multi_dimensional_arrays = vec![];
+
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+
+        for field in model.scalar_fields_mut() {
+            let r#type = match &field.field_type {
+                FieldType::Unsupported(r#type) => r#type,
+                _ => continue,
+            };
+
+            if !r#type.ends_with("[]") {
+                continue;
+            }
+
+            multi_dimensional_arrays.push(ModelAndFieldAndType {
+                model: model_name.clone(),
+                field: field.name.clone(),
+                tpe: r#type.clone(),
+            })
+        }
+    }
+
+    multi_dimensional_arrays
+}
+
+// Postgres range columns (`int4range`, `tstzrange`, etc.), which are mapped to `Unsupported`
+// because Prisma has no equivalent type, but get their own, more specific warning.
+fn range_types(datamodel: &mut Datamodel) -> Vec<ModelAndFieldAndType> {
+    let mut range_types = vec![];
+
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+
+        for field in model.scalar_fields_mut() {
+            let r#type = match &field.field_type {
+                FieldType::Unsupported(r#type) => r#type,
+                _ => continue,
+            };
+
+            if !RANGE_TYPES.contains(&r#type.as_str()) {
+                continue;
+            }
+
+            range_types.push(ModelAndFieldAndType {
+                model: model_name.clone(),
+                field: field.name.clone(),
+                tpe: r#type.clone(),
+            })
+        }
+    }
+
+    range_types
+}
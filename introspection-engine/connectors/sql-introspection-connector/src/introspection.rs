@@ -32,6 +32,7 @@ pub(crate) fn introspect(version_check: &mut VersionChecker, ctx: &mut Context)
     {
         debug!("Calculating model: {}", table.name());
         let mut model = Model::new(table.name().to_owned(), None);
+        model.documentation = table.description().map(ToOwned::to_owned);
 
         for column in table.columns() {
             version_check.check_column_for_type_and_default_value(column);
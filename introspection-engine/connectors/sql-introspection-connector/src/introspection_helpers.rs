@@ -4,8 +4,10 @@ use datamodel::{
     dml::{
         Datamodel, FieldArity, FieldType, IndexAlgorithm, IndexDefinition, IndexField, Model, OperatorClass,
         PrimaryKeyField, ReferentialAction, RelationField, RelationInfo, ScalarField, ScalarType, SortOrder,
+        WithDatabaseName,
     },
 };
+use quaint::prelude::SqlFamily;
 use sql::walkers::{ColumnWalker, ForeignKeyWalker, TableWalker};
 use sql_schema_describer::{
     self as sql, mssql::MssqlSchemaExt, postgres::PostgresSchemaExt, ColumnArity, ColumnTypeFamily, ForeignKeyAction,
@@ -197,7 +199,37 @@ pub(crate) fn calculate_scalar_field(column: ColumnWalker<'_>, ctx: &mut Context
         ColumnArity::List => FieldArity::List,
     };
 
-    let default_value = crate::defaults::calculate_default(column, ctx);
+    // A generated column's `pg_get_expr` output is its generation expression, not a writable
+    // default, so we do not surface it as `@default(dbgenerated(...))`.
+    let default_value = if column.is_generated() {
+        None
+    } else {
+        crate::defaults::calculate_default(column, ctx)
+    };
+
+    let is_domain_column = ctx.sql_family().is_postgres()
+        && !matches!(field_type, FieldType::Unsupported(_))
+        && {
+            let pg_ext: &PostgresSchemaExt = ctx.schema.downcast_connector_data();
+            pg_ext.is_domain(&column.column_type().full_data_type)
+        };
+
+    let is_hstore_column =
+        ctx.sql_family().is_postgres() && matches!(column.column_type().full_data_type.as_str(), "hstore" | "_hstore");
+
+    let documentation = if column.is_generated() {
+        Some(GENERATED_COLUMN_DOCUMENTATION.to_owned())
+    } else if is_domain_column {
+        Some(DOMAIN_COLUMN_DOCUMENTATION.to_owned())
+    } else if is_hstore_column {
+        Some(HSTORE_COLUMN_DOCUMENTATION.to_owned())
+    } else {
+        None
+    };
+
+    // MySQL's column-level `ON UPDATE CURRENT_TIMESTAMP` is the idiomatic way to express an
+    // `updatedAt` column at the database level, so introspection maps it directly to `@updatedAt`.
+    let is_updated_at = column.auto_updates_to_now() && field_type.is_datetime();
 
     ScalarField {
         name: column.name().to_owned(),
@@ -205,14 +237,33 @@ pub(crate) fn calculate_scalar_field(column: ColumnWalker<'_>, ctx: &mut Context
         field_type,
         database_name: None,
         default_value,
-        documentation: None,
+        documentation,
         is_generated: false,
-        is_updated_at: false,
+        is_updated_at,
         is_commented_out: false,
         is_ignored: false,
     }
 }
 
+/// Marker left on the `documentation` of scalar fields backed by a Postgres domain type (`CREATE
+/// DOMAIN`) that was resolved to its base type, so that
+/// [`crate::warnings::warning_domain_types`] can find them again once the datamodel has settled
+/// into its final shape.
+pub(crate) const DOMAIN_COLUMN_DOCUMENTATION: &str =
+    "This field's database type is a domain, which was mapped to its base type.";
+
+/// Marker left on the `documentation` of scalar fields backed by a database-generated column (e.g.
+/// Postgres' `GENERATED ALWAYS AS (...) STORED`), so that [`crate::warnings::warning_generated_columns`]
+/// can find them again once the datamodel has settled into its final shape.
+pub(crate) const GENERATED_COLUMN_DOCUMENTATION: &str =
+    "This field is a generated/computed column in the database and will not be writable by the Prisma Client.";
+
+/// Marker left on the `documentation` of scalar fields backed by a Postgres `hstore` column (from
+/// the `hstore` contrib extension), so that [`crate::warnings::warning_hstore_types`] can find them
+/// again once the datamodel has settled into its final shape.
+pub(crate) const HSTORE_COLUMN_DOCUMENTATION: &str =
+    "This field's database type is hstore, which is currently mapped to Json.";
+
 pub(crate) fn calculate_relation_field(
     foreign_key: ForeignKeyWalker<'_>,
     m2m_table_names: &[String],
@@ -551,3 +602,99 @@ fn get_opclass(index_field_id: sql::IndexColumnId, schema: &SqlSchema, ctx: &mut
         sql::postgres::SQLOperatorClassKind::Raw(c) => Some(OperatorClass::Raw(c.to_string().into())),
     }
 }
+
+/// The models backed by a table that has one or more CHECK constraints defined on it. Prisma
+/// does not model check constraints, so these are only surfaced as an informational warning.
+pub(crate) fn models_with_check_constraints(
+    schema: &SqlSchema,
+    datamodel: &Datamodel,
+    sql_family: SqlFamily,
+) -> Vec<crate::warnings::Model> {
+    if !sql_family.is_postgres() {
+        return Vec::new();
+    }
+
+    let pg_ext: &PostgresSchemaExt = schema.downcast_connector_data();
+
+    let mut table_ids: Vec<_> = pg_ext.check_constraints.iter().map(|(table_id, _)| *table_id).collect();
+    table_ids.dedup();
+
+    table_ids
+        .into_iter()
+        .filter_map(|table_id| {
+            let table_name = schema.walk(table_id).name();
+
+            datamodel
+                .models()
+                .find(|model| model.final_database_name() == table_name)
+                .map(|model| crate::warnings::Model::new(&model.name))
+        })
+        .collect()
+}
+
+/// The models backed by a table that has one or more EXCLUDE constraints defined on it, together
+/// with the name of the constraint. Prisma does not model exclusion constraints, so these are only
+/// surfaced as an informational warning.
+pub(crate) fn models_with_exclusion_constraints(
+    schema: &SqlSchema,
+    datamodel: &Datamodel,
+    sql_family: SqlFamily,
+) -> Vec<crate::warnings::ModelAndConstraint> {
+    if !sql_family.is_postgres() {
+        return Vec::new();
+    }
+
+    let pg_ext: &PostgresSchemaExt = schema.downcast_connector_data();
+
+    pg_ext
+        .exclusion_constraints
+        .iter()
+        .filter_map(|(table_id, constraint_name)| {
+            let table_name = schema.walk(*table_id).name();
+
+            datamodel
+                .models()
+                .find(|model| model.final_database_name() == table_name)
+                .map(|model| crate::warnings::ModelAndConstraint::new(&model.name, constraint_name))
+        })
+        .collect()
+}
+
+/// The fields backed by a serial-like column whose underlying sequence has a non-default `INCREMENT`
+/// or `START` value. `@default(autoincrement())` can't express those, so the sequence's actual
+/// stepping/starting point is silently lost unless we at least warn about it.
+pub(crate) fn fields_with_non_default_sequences(
+    schema: &SqlSchema,
+    datamodel: &Datamodel,
+    sql_family: SqlFamily,
+) -> Vec<crate::warnings::ModelAndField> {
+    if !sql_family.is_postgres() {
+        return Vec::new();
+    }
+
+    let pg_ext: &PostgresSchemaExt = schema.downcast_connector_data();
+
+    schema
+        .walk_columns()
+        .filter_map(|column| {
+            let sequence_name = match column.default().map(|d| d.kind()) {
+                Some(sql::DefaultKind::Sequence(name)) => name,
+                _ => return None,
+            };
+
+            let (_, sequence) = pg_ext.get_sequence(sequence_name)?;
+
+            if sequence.increment_by == 1 && sequence.start_value == 1 {
+                return None;
+            }
+
+            let table_name = column.table().name();
+            let model = datamodel.models().find(|model| model.final_database_name() == table_name)?;
+            let field = model
+                .scalar_fields()
+                .find(|field| field.final_database_name() == column.name())?;
+
+            Some(crate::warnings::ModelAndField::new(&model.name, &field.name))
+        })
+        .collect()
+}
@@ -3,7 +3,7 @@ use crate::{
     warnings::*,
     SqlFamilyTrait,
 };
-use datamodel::dml::{self, Datamodel, DefaultValue, Field, FieldType, Ignorable, ValueGenerator, WithName};
+use datamodel::dml::{self, Datamodel, DefaultKind, DefaultValue, Field, FieldType, Ignorable, ValueGenerator, WithName};
 use introspection_connector::{IntrospectionContext, Warning};
 use prisma_value::PrismaValue;
 use std::{
@@ -25,7 +25,11 @@ pub fn enrich(
     merge_map_attributes_on_models(old_data_model, new_data_model, warnings);
     merge_pre_3_0_index_names(old_data_model, new_data_model, warnings);
     merge_custom_index_names(old_data_model, new_data_model, warnings);
+    merge_id_field_backed_by_unique_index(old_data_model, new_data_model);
     merge_changed_primary_key_names(old_data_model, new_data_model, warnings);
+    warn_about_changed_primary_key_order(old_data_model, new_data_model, warnings);
+    warn_about_replaced_native_types(old_data_model, new_data_model, warnings);
+    warn_about_changed_scalar_defaults(old_data_model, new_data_model, warnings);
     merge_changed_scalar_key_names(old_data_model, new_data_model, warnings);
     merge_changed_relation_field_names(old_data_model, new_data_model);
     merge_changed_relation_names(old_data_model, new_data_model);
@@ -37,6 +41,7 @@ pub fn enrich(
     merge_ignores(old_data_model, new_data_model, warnings);
     merge_comments(old_data_model, new_data_model);
     keep_index_ordering(old_data_model, new_data_model);
+    keep_field_ordering(old_data_model, new_data_model);
 
     // restore old model order
     new_data_model.models.sort_by(|model_a, model_b| {
@@ -122,6 +127,115 @@ fn merge_changed_enum_defaults(
     }
 }
 
+// The new datamodel's native types always come straight from the current column metadata, so there
+// is nothing to merge here. We only need to warn the user when a `@db` annotation they had written
+// no longer matches what introspection just found, e.g. the column's type changed out from under it.
+fn warn_about_replaced_native_types(
+    old_data_model: &Datamodel,
+    new_data_model: &Datamodel,
+    warnings: &mut Vec<Warning>,
+) {
+    let mut replaced_native_types = vec![];
+
+    for old_model in old_data_model.models() {
+        let new_model = match new_data_model.models().find(|m| m.name == *old_model.name()) {
+            Some(m) => m,
+            None => continue,
+        };
+
+        for old_field in old_model.scalar_fields() {
+            let new_field = match new_model.scalar_fields().find(|f| f.name == *old_field.name()) {
+                Some(f) => f,
+                None => continue,
+            };
+
+            let old_native_type = match &old_field.field_type {
+                FieldType::Scalar(_, Some(nt)) => nt,
+                _ => continue,
+            };
+
+            let new_native_type = match &new_field.field_type {
+                FieldType::Scalar(_, Some(nt)) => nt,
+                _ => continue,
+            };
+
+            if old_native_type.name != new_native_type.name || old_native_type.args != new_native_type.args {
+                replaced_native_types.push(ModelFieldPreviousAndCurrentType::new(
+                    new_model.name(),
+                    new_field.name(),
+                    &render_native_type(old_native_type),
+                    &render_native_type(new_native_type),
+                ));
+            }
+        }
+    }
+
+    if !replaced_native_types.is_empty() {
+        warnings.push(warning_native_type_replaced(&replaced_native_types));
+    }
+}
+
+// The new data model always keeps the freshly introspected default, this only warns so that users
+// relying on a previously hand-picked `@default` aren't silently surprised when the database's
+// default has since diverged from it.
+fn warn_about_changed_scalar_defaults(
+    old_data_model: &Datamodel,
+    new_data_model: &Datamodel,
+    warnings: &mut Vec<Warning>,
+) {
+    let mut changed_defaults = vec![];
+
+    for old_model in old_data_model.models() {
+        let new_model = match new_data_model.models().find(|m| m.name == *old_model.name()) {
+            Some(m) => m,
+            None => continue,
+        };
+
+        for old_field in old_model.scalar_fields() {
+            let new_field = match new_model.scalar_fields().find(|f| f.name == *old_field.name()) {
+                Some(f) => f,
+                None => continue,
+            };
+
+            let (old_default, new_default) = match (&old_field.default_value, &new_field.default_value) {
+                (Some(old_default), Some(new_default)) => (old_default, new_default),
+                _ => continue,
+            };
+
+            if old_default.kind() != new_default.kind() {
+                changed_defaults.push(ModelFieldPreviousAndCurrentValue::new(
+                    new_model.name(),
+                    new_field.name(),
+                    &render_default_value(old_default),
+                    &render_default_value(new_default),
+                ));
+            }
+        }
+    }
+
+    if !changed_defaults.is_empty() {
+        warnings.push(warning_default_value_changed(&changed_defaults));
+    }
+}
+
+fn render_default_value(default: &DefaultValue) -> String {
+    match default.kind() {
+        DefaultKind::Single(v) => v.to_string(),
+        DefaultKind::Expression(e) => {
+            let args: Vec<String> = e.args().iter().map(|(_, v)| v.to_string()).collect();
+            format!("{}({})", e.name(), args.join(", "))
+        }
+    }
+}
+
+fn render_native_type(native_type: &dml::native_type_instance::NativeTypeInstance) -> String {
+    if native_type.args.is_empty() {
+        native_type.name.clone()
+    } else {
+        format!("{}({})", native_type.name, native_type.args.join(", "))
+    }
+}
+
 fn keep_index_ordering(old_data_model: &Datamodel, new_data_model: &mut Datamodel) {
     for old_model in old_data_model.models() {
         let new_model = match new_data_model.models_mut().find(|m| m.name == *old_model.name()) {
@@ -154,44 +268,25 @@ fn merge_relation_fields(old_data_model: &Datamodel, new_data_model: &mut Datamo
     let mut changed_models = BTreeSet::new();
 
     for old_model in old_data_model.models() {
-        let modifications = new_data_model
-            .models()
-            .find(|m| m.name == *old_model.name())
-            .map(|new_model| {
-                let mut ordering: HashMap<String, usize> = old_model
-                    .fields()
-                    .enumerate()
-                    .map(|(i, field)| (field.name().to_string(), i))
-                    .collect();
-
-                for (i, field) in new_model.fields().enumerate() {
-                    if !ordering.contains_key(field.name()) {
-                        ordering.insert(field.name().to_string(), i);
-                    }
-                }
-
-                let mut fields = Vec::new();
+        let modifications = new_data_model.models().find(|m| m.name == *old_model.name()).map(|_| {
+            let mut fields = Vec::new();
 
-                for field in old_model.relation_fields() {
-                    if new_data_model.models().any(|m| m.name == field.relation_info.to) {
-                        fields.push(Field::RelationField(field.clone()));
-                    }
+            for field in old_model.relation_fields() {
+                if new_data_model.models().any(|m| m.name == field.relation_info.to) {
+                    fields.push(Field::RelationField(field.clone()));
                 }
+            }
 
-                (new_model.name().to_string(), fields, ordering)
-            });
+            (old_model.name().to_string(), fields)
+        });
 
-        if let Some((model_name, fields, ordering)) = modifications {
+        if let Some((model_name, fields)) = modifications {
             let new_model = new_data_model.find_model_mut(&model_name);
 
             for field in fields.into_iter() {
                 changed_models.insert(new_model.name().to_string());
                 new_model.add_field(field);
             }
-
-            new_model
-                .fields
-                .sort_by_cached_key(|field| *ordering.get(field.name()).unwrap_or(&usize::MAX));
         }
     }
 
@@ -201,6 +296,48 @@ fn merge_relation_fields(old_data_model: &Datamodel, new_data_model: &mut Datamo
     }
 }
 
+// Fields present in the previous schema keep their relative position; columns that introspection
+// only just discovered (i.e. newly added DB columns, or relation fields re-added by
+// `merge_relation_fields`) are appended after them, in the order introspection found them.
+fn keep_field_ordering(old_data_model: &Datamodel, new_data_model: &mut Datamodel) {
+    for old_model in old_data_model.models() {
+        let new_model = match new_data_model.models_mut().find(|m| m.name == *old_model.name()) {
+            Some(m) => m,
+            None => continue,
+        };
+
+        let mut ordering: HashMap<String, usize> = old_model
+            .fields()
+            .enumerate()
+            .map(|(i, field)| (field.name().to_string(), i))
+            .collect();
+
+        let mut next_new_field_idx = ordering.len();
+
+        for field in new_model.fields() {
+            ordering.entry(field.name().to_string()).or_insert_with(|| {
+                let idx = next_new_field_idx;
+                next_new_field_idx += 1;
+                idx
+            });
+        }
+
+        new_model
+            .fields
+            .sort_by_cached_key(|field| *ordering.get(field.name()).unwrap_or(&usize::MAX));
+    }
+}
+
+// A conservative list of common SQL reserved words. A table named one of these still
+// introspects fine on its own (Prisma always quotes identifiers in the generated queries), but
+// if the model itself doesn't also carry an explicit `@@map`, re-introspecting a schema that
+// previously quoted the name into one is surprising. We re-emit `@@map` for these so the mapping
+// doesn't flicker in and out depending on whether the previous schema happened to have it.
+const RESERVED_SQL_WORDS: &[&str] = &[
+    "column", "check", "constraint", "default", "foreign", "group", "having", "index", "join", "key", "order",
+    "primary", "references", "select", "table", "union", "unique", "user", "where",
+];
+
 //@@map on models
 fn merge_map_attributes_on_models(
     old_data_model: &Datamodel,
@@ -208,13 +345,33 @@ fn merge_map_attributes_on_models(
     warnings: &mut Vec<Warning>,
 ) {
     let mut changed_model_names = vec![];
+    let mut reserved_word_model_names = vec![];
 
     for model in new_data_model.models() {
-        if let Some(old_model) = old_data_model.find_model_db_name(model.database_name.as_ref().unwrap_or(&model.name))
-        {
+        let db_name = model.database_name.as_ref().unwrap_or(&model.name);
+
+        if let Some(old_model) = old_data_model.find_model_db_name(db_name) {
             if new_data_model.find_model(&old_model.name).is_none() {
                 changed_model_names.push((Model::new(&model.name), Model::new(&old_model.name)))
             }
+        } else if model.database_name.is_none() {
+            // The table name didn't change, but it differs from the Prisma model name only by
+            // casing (e.g. table `user`, model `User`). `find_model_db_name` is a case-sensitive
+            // lookup against an explicit `@@map`, so a pure casing difference against the old
+            // model's own (unmapped) name wouldn't be found above, and the model would silently
+            // lose its `@@map` on re-introspection.
+            let old_db_name_match = old_data_model.models().find(|m| {
+                let old_db_name = m.database_name.as_deref().unwrap_or(&m.name);
+                old_db_name.eq_ignore_ascii_case(db_name) && m.name != model.name
+            });
+
+            if let Some(old_model) = old_db_name_match {
+                if new_data_model.find_model(&old_model.name).is_none() {
+                    changed_model_names.push((Model::new(&model.name), Model::new(&old_model.name)))
+                }
+            } else if RESERVED_SQL_WORDS.contains(&db_name.to_lowercase().as_str()) {
+                reserved_word_model_names.push(Model::new(&model.name));
+            }
         }
     }
 
@@ -227,6 +384,14 @@ fn merge_map_attributes_on_models(
         };
     }
 
+    // re-emit `@@map` for models whose table name is a SQL reserved word.
+    for reserved in &reserved_word_model_names {
+        let model = new_data_model.find_model_mut(&reserved.model);
+        if model.database_name.is_none() {
+            model.database_name = Some(model.name.clone());
+        }
+    }
+
     // change relation types
     for changed_model_name in &changed_model_names {
         let fields_to_be_changed = new_data_model.find_relation_fields_for_model(&changed_model_name.0.model);
@@ -237,9 +402,11 @@ fn merge_map_attributes_on_models(
         }
     }
 
-    if !changed_model_names.is_empty() {
-        let models: Vec<_> = changed_model_names.iter().map(|c| c.1.clone()).collect();
-        warnings.push(warning_enriched_with_map_on_model(&models));
+    let mut mapped_models: Vec<_> = changed_model_names.into_iter().map(|c| c.1).collect();
+    mapped_models.extend(reserved_word_model_names);
+
+    if !mapped_models.is_empty() {
+        warnings.push(warning_enriched_with_map_on_model(&mapped_models));
     }
 }
 
@@ -321,6 +488,55 @@ fn merge_custom_index_names(old_data_model: &Datamodel, new_data_model: &mut Dat
     }
 }
 
+// Some databases (and some schema histories) end up representing what used to be the primary key
+// as a plain unique index instead of a declared primary key constraint. Introspection on its own
+// can only see the unique index and would render that field as `@unique`, silently demoting it.
+// If the previous schema had that exact single field as `@id`, keep it that way rather than
+// dropping the user down to `@unique`.
+fn merge_id_field_backed_by_unique_index(old_data_model: &Datamodel, new_data_model: &mut Datamodel) {
+    let mut promoted_models = vec![];
+
+    for model in new_data_model.models() {
+        if model.primary_key.is_some() {
+            continue;
+        }
+
+        let old_model = match old_data_model.find_model(&model.name) {
+            Some(old_model) => old_model,
+            None => continue,
+        };
+
+        let old_id_field = match &old_model.primary_key {
+            Some(pk) if pk.defined_on_field && pk.fields.len() == 1 => &pk.fields[0].name,
+            _ => continue,
+        };
+
+        if model
+            .indices
+            .iter()
+            .any(|index| index.is_unique() && index.fields.len() == 1 && index.fields[0].path[0].0 == *old_id_field)
+        {
+            promoted_models.push((model.name.clone(), old_id_field.clone()));
+        }
+    }
+
+    for (model_name, id_field) in promoted_models {
+        let model = new_data_model.find_model_mut(&model_name);
+
+        model
+            .indices
+            .retain(|index| !(index.is_unique() && index.fields.len() == 1 && index.fields[0].path[0].0 == id_field));
+
+        model.primary_key = Some(dml::PrimaryKeyDefinition {
+            name: None,
+            db_name: None,
+            fields: vec![dml::PrimaryKeyField::new(&id_field)],
+            defined_on_field: true,
+            clustered: None,
+        });
+    }
+}
+
 //custom primary key names
 fn merge_changed_primary_key_names(
     old_data_model: &Datamodel,
@@ -365,6 +581,47 @@ fn merge_changed_primary_key_names(
     }
 }
 
+// The `@@id` field order is always taken from the database's authoritative column order (see
+// `introspection.rs`), so a schema re-introspected after the database's primary key column order
+// changed out from under it will silently reorder the `@@id` attribute. We don't want to fight the
+// database here, since keeping the Prisma schema in sync with reality is the whole point of
+// re-introspection, but we do want to flag it, since client code generated against the old field
+// order (e.g. positional arguments or destructuring) could break.
+fn warn_about_changed_primary_key_order(
+    old_data_model: &Datamodel,
+    new_data_model: &Datamodel,
+    warnings: &mut Vec<Warning>,
+) {
+    let mut changed_primary_key_orders = vec![];
+
+    for model in new_data_model.models() {
+        if let Some(old_model) = &old_data_model.find_model(&model.name) {
+            if let Some(primary_key) = &model.primary_key {
+                if let Some(old_primary_key) = &old_model.primary_key {
+                    let old_names: Vec<_> = old_primary_key.fields.iter().map(|f| &f.name).collect();
+                    let new_names: Vec<_> = primary_key.fields.iter().map(|f| &f.name).collect();
+
+                    let same_fields = {
+                        let mut old_sorted = old_names.clone();
+                        let mut new_sorted = new_names.clone();
+                        old_sorted.sort();
+                        new_sorted.sort();
+                        old_sorted == new_sorted
+                    };
+
+                    if same_fields && old_names != new_names {
+                        changed_primary_key_orders.push(Model::new(&model.name));
+                    }
+                }
+            }
+        }
+    }
+
+    if !changed_primary_key_orders.is_empty() {
+        warnings.push(warning_primary_key_order_changed(&changed_primary_key_orders));
+    }
+}
+
 // @map on fields
 fn merge_changed_scalar_key_names(
     old_data_model: &Datamodel,
@@ -470,6 +727,22 @@ fn merge_changed_relation_field_names(old_data_model: &Datamodel, new_data_model
                 let match_as_inline = inline_relation_infos_match(&old_field.relation_info, &new_field.relation_info)
                     && inline_relation_infos_match(&old_related_field.relation_info, &related_field.relation_info);
 
+                // For a 1:1 relation where the FK column carries a `@map`, the freshly introspected
+                // scalar field name can still differ from the old, custom Prisma name at the point this
+                // runs in some edge cases, which would make the strict comparison above miss the match.
+                // Falling back to comparing by the underlying database column names keeps the relation
+                // identifiable regardless of what either side's Prisma field happens to be named.
+                let match_as_inline = match_as_inline
+                    || (!is_many_to_many
+                        && relation_infos_match_by_db_name(
+                            old_data_model,
+                            old_model,
+                            &old_field.relation_info,
+                            new_data_model,
+                            new_model,
+                            &new_field.relation_info,
+                        ));
+
                 let mf = ModelAndField::new(&new_model.name, &new_field.name);
 
                 if match_as_inline
@@ -665,8 +938,13 @@ fn merge_mysql_enum_names(old_data_model: &Datamodel, new_data_model: &mut Datam
 
             let old_enum = old_data_model.find_enum(&old_enum_name).unwrap();
 
+            // The old enum name might already be in use by another new enum — e.g. it was just
+            // restored onto a different field by `merge_changed_enum_names` because of an
+            // `@@map` on the shared old enum. Renaming this one too would produce two enums with
+            // the same name.
             if enm.values == old_enum.values
                 && old_enum_name != enm.name
+                && new_data_model.find_enum(&old_enum_name).is_none()
                 && !changed_mysql_enum_names
                     .iter()
                     .any(|x: &(String, String, ModelAndField)| x.1 == old_enum_name)
@@ -724,6 +1002,16 @@ fn merge_prisma_level_defaults(
                 }
             }
 
+            // A server-side `gen_random_uuid()`/`uuid_generate_v4()` default is introspected as
+            // `@default(dbgenerated(...))`. If the previous schema modeled the same column as the
+            // Prisma-level `@default(uuid())` instead, keep it that way across re-introspection.
+            if field.field_type.is_string()
+                && is_uuid_generator_default(field.default_value.as_ref())
+                && old_field.default_value == Some(DefaultValue::new_expression(ValueGenerator::new_uuid()))
+            {
+                re_introspected_prisma_level_uuids.push(ModelAndField::new(&model.name, &field.name));
+            }
+
             if field.field_type.is_datetime() && old_field.is_updated_at {
                 re_introspected_updated_at.push(ModelAndField::new(&model.name, &field.name));
             }
@@ -793,6 +1081,21 @@ fn merge_ignores(old_data_model: &Datamodel, new_data_model: &mut Datamodel, war
         new_data_model.find_model_mut(&ignore.model).is_ignored = true;
     }
 
+    // `commenting_out_guardrails` removes backrelations for models it ignores itself (e.g. for
+    // missing a unique identifier), but it runs after `enrich` and knows nothing about ignores we
+    // are only re-applying here from the previous schema. Do the same for those, so a newly added
+    // foreign key on a re-ignored model doesn't surface as a dangling, non-ignored relation field.
+    for ignore in &re_introspected_model_ignores {
+        for model in new_data_model.models_mut() {
+            let model_is_ignored = model.is_ignored;
+            for field in model.relation_fields_mut() {
+                if field.points_to_model(&ignore.model) && !model_is_ignored {
+                    field.is_ignored = true;
+                }
+            }
+        }
+    }
+
     for ignore in &re_introspected_field_ignores {
         new_data_model.find_field_mut(&ignore.model, &ignore.field).ignore();
     }
@@ -849,16 +1152,19 @@ fn merge_comments(old_data_model: &Datamodel, new_data_model: &mut Datamodel) {
     let mut re_introspected_enum_value_comments = vec![];
 
     for enm in new_data_model.enums() {
-        for value in &enm.values {
-            let old_enum = match old_data_model.find_enum(&enm.name) {
-                Some(old_enum) => old_enum,
-                None => continue,
-            };
+        let old_enum = match old_data_model.find_enum(&enm.name) {
+            Some(old_enum) => old_enum,
+            None => continue,
+        };
 
-            if old_enum.documentation.is_some() {
-                re_introspected_enum_comments.push((Enum::new(&enm.name), &old_enum.documentation))
-            }
+        if old_enum.documentation.is_some() {
+            re_introspected_enum_comments.push((Enum::new(&enm.name), &old_enum.documentation))
+        }
 
+        for value in &enm.values {
+            // By this point `merge_changed_enum_values` has already realigned a `@map`-ped value's
+            // name back to the old friendly name, so a plain name lookup finds the right old value
+            // for both mapped and unmapped values.
             let old_value = match old_enum.find_value(&value.name) {
                 Some(old_value) => old_value,
                 None => continue,
@@ -888,3 +1194,53 @@ fn merge_comments(old_data_model: &Datamodel, new_data_model: &mut Datamodel) {
 fn inline_relation_infos_match(a: &dml::RelationInfo, b: &dml::RelationInfo) -> bool {
     a.to == b.to && a.fields == b.fields && a.references == b.references
 }
+
+// `gen_random_uuid()` (Postgres) and `uuid_generate_v4()` (the `uuid-ossp` extension) are the two
+// common server-side defaults that produce the same kind of value as `@default(uuid())`.
+fn is_uuid_generator_default(default_value: Option<&DefaultValue>) -> bool {
+    let description = match default_value.and_then(|default| default.to_dbgenerated_func()) {
+        Some(description) => description,
+        None => return false,
+    };
+
+    let normalized = description.trim().to_lowercase();
+
+    normalized == "gen_random_uuid()" || normalized == "uuid_generate_v4()"
+}
+
+fn scalar_db_names(model: &dml::Model, field_names: &[String]) -> Vec<String> {
+    field_names
+        .iter()
+        .map(|name| {
+            model
+                .find_scalar_field(name)
+                .and_then(|field| field.database_name.clone())
+                .unwrap_or_else(|| name.clone())
+        })
+        .collect()
+}
+
+// Same idea as `inline_relation_infos_match`, but identifies the FK fields by their underlying
+// database column name instead of their (possibly not yet re-mapped) Prisma field name.
+fn relation_infos_match_by_db_name(
+    old_data_model: &Datamodel,
+    old_owner: &dml::Model,
+    old_info: &dml::RelationInfo,
+    new_data_model: &Datamodel,
+    new_owner: &dml::Model,
+    new_info: &dml::RelationInfo,
+) -> bool {
+    if old_info.to != new_info.to || scalar_db_names(old_owner, &old_info.fields) != scalar_db_names(new_owner, &new_info.fields) {
+        return false;
+    }
+
+    match (
+        old_data_model.find_model(&old_info.to),
+        new_data_model.find_model(&new_info.to),
+    ) {
+        (Some(old_related), Some(new_related)) => {
+            scalar_db_names(old_related, &old_info.references) == scalar_db_names(new_related, &new_info.references)
+        }
+        _ => false,
+    }
+}
@@ -72,6 +72,59 @@ impl ModelAndIndex {
     }
 }
 
+#[derive(Serialize, Debug, Clone)]
+pub struct ModelAndConstraint {
+    pub(crate) model: String,
+    pub(crate) constraint: String,
+}
+
+impl ModelAndConstraint {
+    pub fn new(model: &str, constraint: &str) -> Self {
+        ModelAndConstraint {
+            model: model.to_owned(),
+            constraint: constraint.to_owned(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ModelFieldPreviousAndCurrentType {
+    pub(crate) model: String,
+    pub(crate) field: String,
+    pub(crate) previous: String,
+    pub(crate) current: String,
+}
+
+impl ModelFieldPreviousAndCurrentType {
+    pub fn new(model: &str, field: &str, previous: &str, current: &str) -> Self {
+        ModelFieldPreviousAndCurrentType {
+            model: model.to_owned(),
+            field: field.to_owned(),
+            previous: previous.to_owned(),
+            current: current.to_owned(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ModelFieldPreviousAndCurrentValue {
+    pub(crate) model: String,
+    pub(crate) field: String,
+    pub(crate) previous: String,
+    pub(crate) current: String,
+}
+
+impl ModelFieldPreviousAndCurrentValue {
+    pub fn new(model: &str, field: &str, previous: &str, current: &str) -> Self {
+        ModelFieldPreviousAndCurrentValue {
+            model: model.to_owned(),
+            field: field.to_owned(),
+            previous: previous.to_owned(),
+            current: current.to_owned(),
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct ModelAndFieldAndType {
     pub(crate) model: String,
@@ -269,3 +322,117 @@ pub fn warning_enum_defaults_added_from_the_previous_data_model(affected: &[Mode
         affected: serde_json::to_value(affected).unwrap(),
     }
 }
+
+pub fn warning_generated_columns(affected: &[ModelAndField]) -> Warning {
+    Warning {
+        code: 21,
+        message: "These fields are generated columns in the database. Prisma currently does not support generated columns, so their values will not be updatable through the Prisma Client.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_check_constraints(affected: &[Model]) -> Warning {
+    Warning {
+        code: 22,
+        message: "These models are affected by an unsupported feature: CHECK constraints. Their corresponding database tables have one or more check constraints defined, which Prisma does not support. The constraints will still be enforced by the database, but they are not reflected in the Prisma schema.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_spatial_types(affected: &[ModelAndFieldAndType]) -> Warning {
+    Warning {
+        code: 23,
+        message: "Spatial types are not supported and were marked Unsupported.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_primary_key_order_changed(affected: &[Model]) -> Warning {
+    Warning {
+        code: 24,
+        message: "The order of the fields in the compound id field of these models was changed to match the order in the database. Consider keeping the previous order if it is important for query parameter order or pattern matching.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_domain_types(affected: &[ModelAndField]) -> Warning {
+    Warning {
+        code: 25,
+        message: "Column uses a domain; mapped to its base type.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_exclusion_constraints(affected: &[ModelAndConstraint]) -> Warning {
+    Warning {
+        code: 26,
+        message: "Exclusion constraint not represented".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_native_type_replaced(affected: &[ModelFieldPreviousAndCurrentType]) -> Warning {
+    Warning {
+        code: 27,
+        message: "Native type replaced due to mismatch".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_hstore_types(affected: &[ModelAndField]) -> Warning {
+    Warning {
+        code: 28,
+        message: "hstore mapped to Json (lossy)".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_multi_dimensional_arrays(affected: &[ModelAndFieldAndType]) -> Warning {
+    Warning {
+        code: 29,
+        message: "Multi-dimensional arrays are not supported and were marked Unsupported.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_range_types(affected: &[ModelAndFieldAndType]) -> Warning {
+    Warning {
+        code: 30,
+        message: "Range types are not supported and were marked Unsupported.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_default_value_changed(affected: &[ModelFieldPreviousAndCurrentValue]) -> Warning {
+    Warning {
+        code: 31,
+        message: "Default value changed from previous schema".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_non_default_sequence(affected: &[ModelAndField]) -> Warning {
+    Warning {
+        code: 32,
+        message: "Sequence has non-default parameters not represented".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+/// Warnings are pushed in the order their checks happen to run, not in a stable order. Sort them
+/// by code, then by the name of the first affected item, so the resulting JSON is deterministic
+/// regardless of which checks produced warnings.
+pub(crate) fn sort_warnings(warnings: &mut [Warning]) {
+    warnings.sort_by(|a, b| a.code.cmp(&b.code).then_with(|| first_affected_name(a).cmp(&first_affected_name(b))));
+}
+
+fn first_affected_name(warning: &Warning) -> &str {
+    warning
+        .affected
+        .as_array()
+        .and_then(|affected| affected.first())
+        .and_then(|first| first.as_object())
+        .and_then(|obj| obj.get("model").or_else(|| obj.get("enm")))
+        .and_then(|name| name.as_str())
+        .unwrap_or("")
+}
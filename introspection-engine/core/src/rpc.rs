@@ -129,6 +129,8 @@ impl RpcImpl {
                             &introspection_result.data_model,
                             &config,
                         ),
+                        datamodel_json: serde_json::to_value(&introspection_result.data_model)
+                            .unwrap_or(serde_json::Value::Null),
                         warnings: introspection_result.warnings,
                         version: introspection_result.version,
                     })
@@ -189,6 +189,14 @@ impl TestApi {
         Ok(serde_json::to_string(&introspection_result.warnings)?)
     }
 
+    /// The introspected datamodel, serialized as a JSON AST instead of the rendered Prisma schema string.
+    pub async fn re_introspect_json(&self, data_model_string: &str) -> Result<String> {
+        let data_model = parse_datamodel(data_model_string);
+        let introspection_result = self.test_introspect_internal(data_model).await?;
+
+        Ok(serde_json::to_string(&introspection_result.data_model)?)
+    }
+
     pub async fn introspect_version(&self) -> Result<Version> {
         let introspection_result = self.test_introspect_internal(Datamodel::new()).await?;
 
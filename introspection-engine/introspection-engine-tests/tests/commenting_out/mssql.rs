@@ -1,5 +1,6 @@
 use barrel::types;
-use introspection_engine_tests::test_api::*;
+use introspection_engine_tests::{assert_eq_json, test_api::*};
+use serde_json::json;
 
 #[test_connector(tags(Mssql))]
 async fn a_table_without_uniques_should_ignore(api: &TestApi) -> TestResult {
@@ -42,3 +43,45 @@ async fn a_table_without_uniques_should_ignore(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_connector(tags(Mssql))]
+async fn computed_columns_are_annotated_with_a_warning(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Box", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("width", types::integer());
+                t.add_column("height", types::integer());
+                t.inject_custom("area AS (width * height)");
+            });
+        })
+        .await?;
+
+    let expected = json!([{
+        "code": 21,
+        "message": "These fields are generated columns in the database. Prisma currently does not support generated columns, so their values will not be updatable through the Prisma Client.",
+        "affected": [
+            {
+                "model": "Box",
+                "field": "area"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    let dm = expect![[r#"
+        model Box {
+          id     Int  @id @default(autoincrement())
+          width  Int
+          height Int
+
+          /// This field is a generated/computed column in the database and will not be writable by the Prisma Client.
+          area   Int?
+        }
+    "#]];
+
+    dm.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use barrel::types;
-use introspection_engine_tests::{test_api::*, TestResult};
+use introspection_engine_tests::{assert_eq_json, test_api::*, TestResult};
+use serde_json::json;
 
 #[test_connector(tags(Mysql))]
 async fn a_table_without_required_uniques(api: &TestApi) -> TestResult {
@@ -67,3 +68,40 @@ async fn a_table_without_uniques_should_ignore(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_connector(tags(Mysql), exclude(Vitess))]
+async fn spatial_types_are_annotated_with_a_warning(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Venue", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("location point not null");
+            });
+        })
+        .await?;
+
+    let expected = json!([{
+        "code": 23,
+        "message": "Spatial types are not supported and were marked Unsupported.",
+        "affected": [
+            {
+                "model": "Venue",
+                "field": "location",
+                "tpe": "point"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    let dm = expect![[r#"
+        model Venue {
+          id       Int                    @id @default(autoincrement())
+          location Unsupported("point")
+        }
+    "#]];
+
+    dm.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
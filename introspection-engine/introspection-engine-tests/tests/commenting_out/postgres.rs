@@ -232,6 +232,48 @@ async fn commenting_out_a_table_without_columns(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn warnings_of_different_kinds_are_sorted_by_code(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Empty", |_t| {});
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::integer().unique(true));
+                t.add_column("broken", types::custom("tsvector"));
+            });
+        })
+        .await?;
+
+    // `Empty` (code 14) is detected before `Test`'s unsupported type (code 3) by the
+    // commenting-out checks, but the warnings array should come back ordered by code.
+    let expected = json!([
+        {
+            "code": 3,
+            "message": "These fields are not supported by the Prisma Client, because Prisma currently does not support their types.",
+            "affected": [
+                {
+                    "model": "Test",
+                    "field": "broken",
+                    "tpe": "tsvector"
+                }
+            ]
+        },
+        {
+            "code": 14,
+            "message": "The following models were commented out as we could not retrieve columns for them. Please check your privileges.",
+            "affected": [
+                {
+                    "model": "Empty"
+                }
+            ]
+        }
+    ]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 async fn ignore_on_back_relation_field_if_pointing_to_ignored_model(api: &TestApi) -> TestResult {
     api.barrel()
@@ -269,3 +311,302 @@ async fn ignore_on_back_relation_field_if_pointing_to_ignored_model(api: &TestAp
 
     Ok(())
 }
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn generated_columns_are_annotated_with_a_warning(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Box", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("width", types::integer());
+                t.add_column("height", types::integer());
+                t.inject_custom("area integer generated always as (width * height) stored");
+            });
+        })
+        .await?;
+
+    let expected = json!([{
+        "code": 21,
+        "message": "These fields are generated columns in the database. Prisma currently does not support generated columns, so their values will not be updatable through the Prisma Client.",
+        "affected": [
+            {
+                "model": "Box",
+                "field": "area"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    let dm = expect![[r#"
+        model Box {
+          id     Int  @id @default(autoincrement())
+          width  Int
+          height Int
+
+          /// This field is a generated/computed column in the database and will not be writable by the Prisma Client.
+          area   Int?
+        }
+    "#]];
+
+    dm.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn check_constraints_are_annotated_with_a_warning(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Product", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("price", types::integer());
+                t.inject_custom("constraint product_price_check check (price > 0)");
+            });
+        })
+        .await?;
+
+    let expected = json!([{
+        "code": 22,
+        "message": "These models are affected by an unsupported feature: CHECK constraints. Their corresponding database tables have one or more check constraints defined, which Prisma does not support. The constraints will still be enforced by the database, but they are not reflected in the Prisma schema.",
+        "affected": [
+            {
+                "model": "Product"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn composite_types_are_commented_out_gracefully(api: &TestApi) -> TestResult {
+    api.raw_cmd("CREATE TYPE address AS (street text, city text)").await;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Company", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("headquarters", types::custom("address"));
+            });
+        })
+        .await?;
+
+    let expected = json!([{
+        "code": 3,
+        "message": "These fields are not supported by the Prisma Client, because Prisma currently does not support their types.",
+        "affected": [
+            {
+                "model": "Company",
+                "field": "headquarters",
+                "tpe": "address"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    let dm = expect![[r#"
+        model Company {
+          id           Int                     @id @default(autoincrement())
+          headquarters Unsupported("address")?
+        }
+    "#]];
+
+    dm.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn domain_columns_are_mapped_to_their_base_type_with_a_warning(api: &TestApi) -> TestResult {
+    api.raw_cmd("CREATE DOMAIN email_address AS VARCHAR(255)").await;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("email", types::custom("email_address"));
+            });
+        })
+        .await?;
+
+    let expected = json!([{
+        "code": 25,
+        "message": "Column uses a domain; mapped to its base type.",
+        "affected": [
+            {
+                "model": "User",
+                "field": "email"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    let dm = expect![[r#"
+        model User {
+          id    Int    @id @default(autoincrement())
+
+          /// This field's database type is a domain, which was mapped to its base type.
+          email String @db.VarChar(255)
+        }
+    "#]];
+
+    dm.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn hstore_columns_are_mapped_to_json_with_a_warning(api: &TestApi) -> TestResult {
+    api.raw_cmd("CREATE EXTENSION IF NOT EXISTS hstore").await;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("attributes hstore not null");
+            });
+        })
+        .await?;
+
+    let expected = json!([{
+        "code": 28,
+        "message": "hstore mapped to Json (lossy)",
+        "affected": [
+            {
+                "model": "User",
+                "field": "attributes"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    let dm = expect![[r#"
+        model User {
+          id         Int  @id @default(autoincrement())
+
+          /// This field's database type is hstore, which is currently mapped to Json.
+          attributes Json
+        }
+    "#]];
+
+    dm.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn multi_dimensional_arrays_are_marked_unsupported_with_a_warning(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "User" (
+            id     SERIAL PRIMARY KEY,
+            matrix INTEGER[][] NOT NULL
+        );
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expected = json!([{
+        "code": 29,
+        "message": "Multi-dimensional arrays are not supported and were marked Unsupported.",
+        "affected": [
+            {
+                "model": "User",
+                "field": "matrix",
+                "tpe": "_int4[]"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    let dm = expect![[r#"
+        model User {
+          id     Int                      @id @default(autoincrement())
+          matrix Unsupported("_int4[]")[]
+        }
+    "#]];
+
+    dm.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn range_types_are_marked_unsupported_with_a_warning(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "Booking" (
+            id       SERIAL PRIMARY KEY,
+            validity INT4RANGE NOT NULL
+        );
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expected = json!([{
+        "code": 30,
+        "message": "Range types are not supported and were marked Unsupported.",
+        "affected": [
+            {
+                "model": "Booking",
+                "field": "validity",
+                "tpe": "int4range"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    let dm = expect![[r#"
+        model Booking {
+          id       Int                     @id @default(autoincrement())
+          validity Unsupported("int4range")
+        }
+    "#]];
+
+    dm.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn exclusion_constraints_are_annotated_with_a_warning(api: &TestApi) -> TestResult {
+    api.raw_cmd("CREATE EXTENSION IF NOT EXISTS btree_gist").await;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Booking", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("room_id", types::integer());
+                t.inject_custom("during tsrange not null");
+                t.inject_custom("constraint booking_no_overlap exclude using gist (room_id with =, during with &&)");
+            });
+        })
+        .await?;
+
+    let expected = json!([{
+        "code": 26,
+        "message": "Exclusion constraint not represented",
+        "affected": [
+            {
+                "model": "Booking",
+                "constraint": "booking_no_overlap"
+            }
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.introspection_warnings().await?);
+
+    let dm = api.introspect_dml().await?;
+
+    assert!(!dm.contains("@unique"));
+    assert!(!dm.contains("@@unique"));
+
+    Ok(())
+}
+
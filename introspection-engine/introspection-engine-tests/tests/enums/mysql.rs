@@ -1,5 +1,30 @@
 use introspection_engine_tests::test_api::*;
 
+#[test_connector(tags(Mysql))]
+async fn an_enum_defaulting_to_an_empty_string_is_mapped_gracefully(api: &TestApi) -> TestResult {
+    let sql = r#"CREATE TABLE `Book` (
+        `id` INTEGER PRIMARY KEY AUTO_INCREMENT,
+        `color` ENUM ('black', '') NOT NULL DEFAULT ''
+    )"#;
+    api.raw_cmd(sql).await;
+
+    let dm = r#"
+        model Book {
+            id    Int        @id @default(autoincrement())
+            color Book_color @default(EMPTY_ENUM_VALUE)
+        }
+
+        enum Book_color {
+            black
+            EMPTY_ENUM_VALUE @map("")
+        }
+    "#;
+
+    api.assert_eq_datamodels(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Mysql))]
 async fn an_enum_with_invalid_value_names_should_have_them_commented_out(api: &TestApi) -> TestResult {
     let sql = r#"CREATE TABLE `test` ( `threechars` ENUM ('123', 'wow','$§!') );"#;
@@ -100,3 +100,33 @@ async fn native_type_columns_feature_on(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_connector(tags(Mssql))]
+async fn uniqueidentifier_columns_with_a_newid_default_round_trip_on_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id INT IDENTITY, CONSTRAINT [Blog_pkey] PRIMARY KEY ([id])");
+                t.inject_custom("guid UNIQUEIDENTIFIER NOT NULL DEFAULT NEWID()");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id   Int    @id @default(autoincrement())
+          guid String @default(dbgenerated("newid()")) @db.UniqueIdentifier
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id   Int    @id @default(autoincrement())
+          guid String @default(dbgenerated("newid()")) @db.UniqueIdentifier
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
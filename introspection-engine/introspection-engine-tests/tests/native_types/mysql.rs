@@ -1,4 +1,4 @@
-use indoc::formatdoc;
+use indoc::{formatdoc, indoc};
 use introspection_engine_tests::test_api::*;
 use test_macros::test_connector;
 
@@ -61,9 +61,12 @@ async fn native_type_columns_feature_on(api: &TestApi) -> TestResult {
         })
         .await?;
 
+    // MariaDB implicitly assigns `DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP` to the
+    // first TIMESTAMP column in a table when it has no explicit default, hence the extra
+    // `@default(now())`/`@updatedAt` on MariaDB but not on MySQL 8.
     let (json, default) = match api {
         _ if api.tags().contains(Tags::Mysql8) => ("Json", ""),
-        _ if api.tags().contains(Tags::Mariadb) => ("String   @db.LongText", "@default(now())"),
+        _ if api.tags().contains(Tags::Mariadb) => ("String   @db.LongText", "@updatedAt @default(now())"),
         _ => unreachable!(),
     };
 
@@ -122,3 +125,131 @@ async fn native_type_columns_feature_on(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_connector(tags(Mariadb, Mysql8))]
+async fn binary_and_varbinary_columns_round_trip_on_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("fixed binary(16) Not Null");
+                t.inject_custom("variable varbinary(255) Not Null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id       Int   @id
+          fixed    Bytes @db.Binary(16)
+          variable Bytes @db.VarBinary(255)
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id       Int   @id
+          fixed    Bytes @db.Binary(16)
+          variable Bytes @db.VarBinary(255)
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Mariadb, Mysql8))]
+async fn tiny_medium_and_long_text_columns_round_trip_with_distinct_native_types(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("a tinytext Not Null");
+                t.inject_custom("b text Not Null");
+                t.inject_custom("c mediumtext Not Null");
+                t.inject_custom("d longtext Not Null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id Int    @id
+          a  String @db.TinyText
+          b  String @db.Text
+          c  String @db.MediumText
+          d  String @db.LongText
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id Int    @id
+          a  String @db.TinyText
+          b  String @db.Text
+          c  String @db.MediumText
+          d  String @db.LongText
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Mariadb, Mysql8))]
+async fn decimal_default_preserves_scale(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Product", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("price Decimal(10,2) Not Null Default 1.50");
+            });
+        })
+        .await?;
+
+    let expected = expect![[r#"
+        model Product {
+          id    Int     @id
+          price Decimal @default(1.50) @db.Decimal(10, 2)
+        }
+    "#]];
+
+    expected.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Mariadb, Mysql8))]
+async fn on_update_current_timestamp_columns_become_updated_at(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Post", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom(
+                    "updated_at DateTime(3) Not Null Default Current_Timestamp(3) On Update Current_Timestamp(3)",
+                );
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Post {
+          id         Int      @id
+          updated_at DateTime @updatedAt @default(now())
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Post {
+          id         Int      @id
+          updated_at DateTime @updatedAt @default(now())
+        }
+    "#]];
+
+    expected.assert_eq(&api.introspect_dml().await?);
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
@@ -34,6 +34,8 @@ const TYPES: &[(&str, &str)] = &[
     ("money", "Money"),
     ("oid", "Oid"),
     ("inet", "Inet"),
+    ("cidr", "Cidr"),
+    ("macaddr", "MacAddr"),
 ];
 
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
@@ -87,6 +89,8 @@ async fn native_type_columns_feature_on(api: &TestApi) -> TestResult {
             money           Decimal  @db.Money
             oid             Int      @db.Oid
             inet            String   @db.Inet
+            cidr            String   @db.Cidr
+            macaddr         String   @db.MacAddr
           }
     "#};
 
@@ -155,6 +159,288 @@ async fn native_type_array_columns_feature_on(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn money_columns_round_trip_on_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("balance money Not Null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id      Int     @id
+          balance Decimal @db.Money
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id      Int     @id
+          balance Decimal @db.Money
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn xml_columns_round_trip_on_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("content xml Not Null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id      Int    @id
+          content String @db.Xml
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id      Int    @id
+          content String @db.Xml
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn bare_numeric_has_no_native_type_args_while_precise_numeric_keeps_them(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("weight numeric Not Null");
+                t.inject_custom("price numeric(10,2) Not Null");
+            });
+        })
+        .await?;
+
+    let expected = indoc! {r#"
+        model Blog {
+          id     Int     @id
+          weight Decimal
+          price  Decimal @db.Decimal(10, 2)
+        }
+    "#};
+
+    let result = api.introspect().await?;
+
+    api.assert_eq_datamodels(expected, &result);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn bit_and_varbit_columns_round_trip_on_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("flags bit(8) Not Null");
+                t.inject_custom("name varbit Not Null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id    Int    @id
+          flags String @db.Bit(8)
+          name  String @db.VarBit
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id    Int    @id
+          flags String @db.Bit(8)
+          name  String @db.VarBit
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn char_and_varchar_columns_round_trip_on_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("fixed char(10) Not Null");
+                t.inject_custom("variable varchar(10) Not Null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id       Int    @id
+          fixed    String @db.Char(10)
+          variable String @db.VarChar(10)
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id       Int    @id
+          fixed    String @db.Char(10)
+          variable String @db.VarChar(10)
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn varchar_array_columns_preserve_element_length_on_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("tags varchar(255)[] Not Null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id   Int      @id
+          tags String[] @db.VarChar(255)
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id   Int      @id
+          tags String[] @db.VarChar(255)
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn real_and_double_precision_columns_are_introspected_with_distinct_native_types(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("a real Not Null");
+                t.inject_custom("b double precision Not Null");
+            });
+        })
+        .await?;
+
+    // `real` isn't the default native type for `Float`, so it's rendered explicitly. `double
+    // precision` is the default, so it round-trips without an explicit annotation, the same way
+    // e.g. `integer` round-trips as a bare `Int` without `@db.Integer`.
+    let input_dm = indoc! {r#"
+        model Blog {
+          id Int   @id
+          a  Float @db.Real
+          b  Float
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id Int   @id
+          a  Float @db.Real
+          b  Float
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn time_and_timetz_columns_with_precision_round_trip_on_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("start time(2) Not Null");
+                t.inject_custom("start_tz timetz(2) Not Null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id       Int      @id
+          start    DateTime @db.Time(2)
+          start_tz DateTime @db.Timetz(2)
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id       Int      @id
+          start    DateTime @db.Time(2)
+          start_tz DateTime @db.Timetz(2)
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn oid_columns_round_trip_on_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("owner oid Not Null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Blog {
+          id    Int @id
+          owner Int @db.Oid
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Blog {
+          id    Int @id
+          owner Int @db.Oid
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(CockroachDb))]
 async fn cdb_char_is_a_char(api: &TestApi) -> TestResult {
     // https://github.com/prisma/prisma/issues/12281
@@ -4,8 +4,9 @@ mod gist;
 mod spgist;
 
 use indoc::indoc;
-use introspection_engine_tests::test_api::*;
+use introspection_engine_tests::{assert_eq_json, test_api::*};
 use quaint::prelude::Queryable;
+use serde_json::json;
 use test_macros::test_connector;
 
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
@@ -50,6 +51,49 @@ async fn sequences_should_work(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn sequence_with_custom_increment_is_warned_about(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE SEQUENCE "Counter_seq" INCREMENT 2 START 10;
+
+        CREATE TABLE "Counter" (
+            id BigInt NOT NULL DEFAULT nextval('"Counter_seq"') PRIMARY KEY
+        );
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expectation = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        model Counter {
+          id BigInt @id @default(autoincrement())
+        }
+    "#]];
+
+    api.expect_datamodel(&expectation).await;
+
+    let expected_warnings = json!([{
+        "code": 32,
+        "message": "Sequence has non-default parameters not represented",
+        "affected": [{
+            "model": "Counter",
+            "field": "id"
+        }]
+    }]);
+
+    assert_eq_json!(expected_warnings, api.introspection_warnings().await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 async fn dbgenerated_type_casts_should_work(api: &TestApi) -> TestResult {
     api.barrel()
@@ -153,3 +197,42 @@ async fn scalar_list_defaults_work(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn enum_array_default_without_spaces_works(api: &TestApi) -> TestResult {
+    let schema = r#"
+        CREATE TYPE "mood" AS ENUM ('black', 'white');
+
+        CREATE TABLE "defaults" (
+            id TEXT PRIMARY KEY,
+            moods MOOD[] NOT NULL DEFAULT '{black,white}'
+        );
+    "#;
+
+    api.raw_cmd(schema).await;
+
+    let expectation = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        model defaults {
+          id    String @id
+          moods mood[] @default([black, white])
+        }
+
+        enum mood {
+          black
+          white
+        }
+    "#]];
+
+    api.expect_datamodel(&expectation).await;
+
+    Ok(())
+}
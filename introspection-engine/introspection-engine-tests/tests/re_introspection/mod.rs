@@ -60,6 +60,88 @@ async fn mapped_model_name(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(exclude(CockroachDb))]
+async fn mapped_model_name_case_only_difference(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("user", |t| {
+                t.add_column("id", types::integer().increments(true));
+                t.add_constraint("user_pkey", types::primary_constraint(vec!["id"]));
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id               Int         @id @default(autoincrement())
+
+            @@map(name: "user")
+        }
+    "#};
+
+    let final_dm = indoc! {r#"
+        model User {
+            id               Int         @id @default(autoincrement())
+
+            @@map(name: "user")
+        }
+    "#};
+
+    api.assert_eq_datamodels(final_dm, &api.re_introspect(input_dm).await?);
+
+    let expected = json!([{
+        "code": 7,
+        "message": "These models were enriched with `@@map` information taken from the previous Prisma schema.",
+        "affected": [{
+            "model":"User"
+        }]
+    }]);
+
+    assert_eq_json!(expected, api.re_introspect_warnings(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(exclude(CockroachDb))]
+async fn mapped_model_name_reserved_sql_word(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("order", |t| {
+                t.add_column("id", types::integer().increments(true));
+                t.add_constraint("order_pkey", types::primary_constraint(vec!["id"]));
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model order {
+            id               Int         @id @default(autoincrement())
+        }
+    "#};
+
+    let final_dm = indoc! {r#"
+        model order {
+            id               Int         @id @default(autoincrement())
+
+            @@map(name: "order")
+        }
+    "#};
+
+    api.assert_eq_datamodels(final_dm, &api.re_introspect(input_dm).await?);
+
+    let expected = json!([{
+        "code": 7,
+        "message": "These models were enriched with `@@map` information taken from the previous Prisma schema.",
+        "affected": [{
+            "model":"order"
+        }]
+    }]);
+
+    assert_eq_json!(expected, api.re_introspect_warnings(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(exclude(CockroachDb))]
 async fn manually_overwritten_mapped_field_name(api: &TestApi) -> TestResult {
     api.barrel()
@@ -500,6 +582,61 @@ async fn mapped_enum_value_name(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn mapped_enum_value_name_is_kept_when_a_new_value_is_added(api: &TestApi) -> TestResult {
+    let sql = "CREATE Type color as ENUM ('black', 'white', 'red')";
+    api.database().execute_raw(sql, &[]).await?;
+
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("User", move |t| {
+                t.add_column("id", types::primary());
+                t.add_column("color", types::custom("color").nullable(false).default("black"));
+            });
+        })
+        .await?;
+
+    let input_dm = r#"
+        model User {
+            id               Int @id @default(autoincrement())
+            color            color @default(BLACK)
+        }
+
+        enum color {
+            BLACK @map("black")
+            white
+        }
+    "#;
+
+    let final_dm = r#"
+        model User {
+            id               Int @id @default(autoincrement())
+            color            color @default(BLACK)
+        }
+
+        enum color {
+            BLACK @map("black")
+            white
+            red
+        }
+    "#;
+
+    api.assert_eq_datamodels(final_dm, &api.re_introspect(input_dm).await?);
+
+    let expected = json!([{
+        "code": 10,
+        "message": "These enum values were enriched with `@map` information taken from the previous Prisma schema.",
+        "affected" :[{
+            "enm": "color",
+            "value": "BLACK"
+        }]
+    }]);
+
+    assert_eq_json!(expected, api.re_introspect_warnings(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 async fn manually_remapped_enum_value_name(api: &TestApi) -> TestResult {
     let sql = "CREATE Type color as ENUM (\'_black\', \'white\')";
@@ -777,6 +914,39 @@ async fn multiple_changed_relation_names(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(exclude(Mysql, Mssql, CockroachDb, Sqlite))]
+async fn auto_generated_ambiguous_relation_names_are_stable_across_introspections(api: &TestApi) -> TestResult {
+    // Two FKs from `Schedule` to `Employee`, disambiguated purely from scratch (no previous
+    // datamodel to enrich from). The disambiguating suffix is keyed on each FK's own column
+    // name, so which name lands on which field must not depend on introspection run order.
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Employee", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Schedule", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("morningEmployeeId", types::integer().nullable(false));
+                t.add_column("eveningEmployeeId", types::integer().nullable(false));
+
+                t.add_foreign_key(&["morningEmployeeId"], "Employee", &["id"]);
+                t.add_foreign_key(&["eveningEmployeeId"], "Employee", &["id"]);
+            });
+        })
+        .await?;
+
+    let first = api.introspect_dml().await?;
+    let second = api.introspect_dml().await?;
+
+    assert_eq!(first, second);
+
+    assert!(first.contains("Employee_EmployeeToSchedule_eveningEmployeeId"));
+    assert!(first.contains("Employee_EmployeeToSchedule_morningEmployeeId"));
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 async fn custom_virtual_relation_field_names(api: &TestApi) -> TestResult {
     api.barrel()
@@ -932,6 +1102,45 @@ async fn custom_model_order(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(exclude(CockroachDb))]
+async fn custom_field_order(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::integer().increments(true));
+                t.add_column("name", types::text());
+                t.add_column("age", types::integer());
+                t.add_column("email", types::text());
+                t.add_constraint("User_pkey", types::primary_constraint(vec!["id"]));
+            });
+        })
+        .await?;
+
+    // The previous schema reorders `name`/`id` relative to the database's column order, and
+    // doesn't mention `age` at all: `age` was added to the database afterwards and must be
+    // appended last, after the fields whose order was inherited from the previous schema.
+    let input_dm = indoc! {r#"
+        model User {
+            email String
+            name  String
+            id    Int    @id @default(autoincrement())
+        }
+    "#};
+
+    let final_dm = indoc! {r#"
+        model User {
+            email String
+            name  String
+            id    Int    @id @default(autoincrement())
+            age   Int
+        }
+    "#};
+
+    api.assert_eq_datamodels(final_dm, &api.re_introspect(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres))]
 async fn custom_enum_order(api: &TestApi) -> TestResult {
     let sql = "CREATE Type a as ENUM ( \'id\')".to_string();
@@ -1142,6 +1351,68 @@ async fn virtual_cuid_default(api: &TestApi) {
     api.assert_eq_datamodels(final_dm, &api.re_introspect(&input_dm).await.unwrap());
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn virtual_uuid_default_is_kept_over_a_gen_random_uuid_default(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "User" (
+            "id" UUID NOT NULL DEFAULT gen_random_uuid(),
+
+            CONSTRAINT "User_pkey" PRIMARY KEY ("id")
+        );
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id String @id @default(uuid()) @db.Uuid
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model User {
+          id String @id @default(uuid()) @db.Uuid
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn re_introspection_does_not_clobber_a_user_edited_table_comment(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "User" (
+            "id" INTEGER NOT NULL,
+
+            CONSTRAINT "User_pkey" PRIMARY KEY ("id")
+        );
+
+        COMMENT ON TABLE "User" IS 'Comment straight from the database.';
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let input_dm = indoc! {r#"
+        /// A comment the user wrote by hand.
+        model User {
+            id Int @id
+        }
+    "#};
+
+    let expected = expect![[r#"
+        /// A comment the user wrote by hand.
+        model User {
+          id Int @id
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(CockroachDb))]
 async fn virtual_cuid_default_cockroach(api: &TestApi) {
     api.barrel()
@@ -1264,6 +1535,48 @@ async fn comments_should_be_kept(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn comments_on_several_mapped_enum_values_should_be_kept(api: &TestApi) -> TestResult {
+    let sql = "CREATE Type a as ENUM ('A', 'B', 'C')".to_string();
+    api.database().execute_raw(&sql, &[]).await?;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id         Int @id @default(autoincrement())
+        }
+
+        enum a {
+            A // first value
+            B_MAPPED @map("B") // second value
+            C // third value
+        }
+    "#};
+
+    let final_dm = indoc! {r#"
+        model User {
+            id         Int @id @default(autoincrement())
+        }
+
+        enum a {
+            A // first value
+            B_MAPPED @map("B") // second value
+            C // third value
+        }
+    "#};
+
+    api.assert_eq_datamodels(final_dm, &api.re_introspect(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(exclude(Mssql, CockroachDb))]
 async fn updated_at(api: &TestApi) {
     api.barrel()
@@ -1507,16 +1820,89 @@ async fn re_introspecting_mysql_enum_names_if_enum_is_reused(api: &TestApi) -> T
     Ok(())
 }
 
-#[test_connector(tags(Postgres), exclude(CockroachDb))]
-async fn custom_repro(api: &TestApi) -> TestResult {
-    let sql = r#"
-        CREATE TABLE "tag" (
-            id SERIAL PRIMARY KEY,
-            name TEXT NOT NULL UNIQUE
-        );
-
-        CREATE TABLE "Post" (
-            id SERIAL PRIMARY KEY,
+// The shared enum's `@@map` must land back on the field whose synthetic db name it actually
+// matches, and the other field reusing the same enum must still get a freshly generated,
+// uniquely-named enum rather than colliding with the just-restored name.
+#[test_connector(tags(Mysql))]
+async fn re_introspecting_mysql_enum_names_if_mapped_enum_is_reused(api: &TestApi) -> TestResult {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("color  ENUM('black', 'white') Not Null");
+                t.inject_custom("color2  ENUM('black', 'white') Not Null");
+            });
+
+            migration.create_table("Unrelated", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await;
+
+    let input_dm = r#"
+            model User {
+               id               Int @id @default(autoincrement())
+               color            BlackNWhite
+               color2           BlackNWhite
+            }
+
+            enum BlackNWhite{
+                black
+                white
+
+                @@map("User_color")
+            }
+        "#;
+
+    let final_dm = r#"
+             model User {
+               id               Int @id @default(autoincrement())
+               color            BlackNWhite
+               color2           User_color2
+            }
+
+            model Unrelated {
+               id               Int @id @default(autoincrement())
+            }
+
+            enum BlackNWhite{
+                black
+                white
+
+                @@map("User_color")
+            }
+
+            enum User_color2{
+                black
+                white
+            }
+        "#;
+    api.assert_eq_datamodels(final_dm, &api.re_introspect(input_dm).await?);
+
+    let expected = json!([{
+        "code": 9,
+        "message": "These enums were enriched with `@@map` information taken from the previous Prisma schema.",
+        "affected": [{
+            "enm": "BlackNWhite"
+        }]
+    }]);
+
+    assert_eq_json!(expected, api.re_introspect_warnings(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn custom_repro(api: &TestApi) -> TestResult {
+    let sql = r#"
+        CREATE TABLE "tag" (
+            id SERIAL PRIMARY KEY,
+            name TEXT NOT NULL UNIQUE
+        );
+
+        CREATE TABLE "Post" (
+            id SERIAL PRIMARY KEY,
             tag_id INTEGER NOT NULL REFERENCES tag(id)
         );
 
@@ -1628,6 +2014,58 @@ async fn re_introspecting_ignore(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(exclude(Vitess, CockroachDb))]
+async fn re_introspecting_ignore_with_a_newly_added_foreign_key(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", move |t| {
+                t.add_column("id", types::integer().increments(true));
+
+                t.add_constraint("User_pkey", types::primary_constraint(vec!["id"]));
+            });
+
+            migration.create_table("Ignored", move |t| {
+                t.add_column("id", types::integer().increments(true));
+                t.add_column("user_id", types::integer().nullable(true));
+
+                t.add_constraint("Ignored_pkey", types::primary_constraint(vec!["id"]));
+                t.add_foreign_key(&["user_id"], "User", &["id"]);
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id           Int @id @default(autoincrement())
+        }
+
+        model Ignored {
+            id           Int @id @default(autoincrement())
+
+            @@ignore
+        }
+    "#};
+
+    let final_dm = indoc! {r#"
+        model User {
+            id           Int       @id @default(autoincrement())
+            Ignored      Ignored[] @ignore
+        }
+
+        model Ignored {
+            id           Int   @id @default(autoincrement())
+            user_id      Int?
+            User         User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+
+            @@ignore
+        }
+    "#};
+
+    api.assert_eq_datamodels(final_dm, &api.re_introspect(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(exclude(Vitess, CockroachDb, Sqlite))]
 async fn do_not_try_to_keep_custom_many_to_many_self_relation_names(api: &TestApi) -> TestResult {
     // We do not have enough information to correctly assign which field should point to column A in the
@@ -1677,6 +2115,44 @@ async fn do_not_try_to_keep_custom_many_to_many_self_relation_names(api: &TestAp
     Ok(())
 }
 
+#[test_connector(exclude(Mysql, Sqlite, CockroachDb))]
+async fn many_to_many_self_relation_names_are_stable_across_re_introspection(api: &TestApi) -> TestResult {
+    // Re-introspection does not merge these field names from the previous schema; it regenerates
+    // the `<Model>_A`/`<Model>_B` names each time. They round-trip unchanged because the
+    // regenerated names come out the same on every run.
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", move |t| {
+                t.add_column("id", types::integer().increments(true));
+                t.add_constraint("User_pkey", types::primary_constraint(&["id"]));
+            });
+
+            migration.create_table("_FollowRelation", |t| {
+                t.add_column("A", types::integer().nullable(false).unique(false));
+                t.add_column("B", types::integer().nullable(false).unique(false));
+
+                t.add_foreign_key(&["A"], "User", &["id"]);
+                t.add_foreign_key(&["B"], "User", &["id"]);
+
+                t.add_index("test", types::index(vec!["A", "B"]).unique(true));
+                t.add_index("test2", types::index(vec!["B"]).unique(false));
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+          id     Int    @id @default(autoincrement())
+          User_B User[] @relation("FollowRelation")
+          User_A User[] @relation("FollowRelation")
+        }
+    "#};
+
+    api.assert_eq_datamodels(input_dm, &api.re_introspect(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres, Mssql), exclude(CockroachDb))]
 async fn re_introspecting_custom_compound_unique_names(api: &TestApi) -> TestResult {
     api.barrel()
@@ -1878,6 +2354,81 @@ async fn re_introspecting_custom_compound_id_names(api: &TestApi) -> TestResult
     Ok(())
 }
 
+#[test_connector(tags(Postgres, Mssql), exclude(CockroachDb))]
+async fn re_introspecting_reordered_compound_id_columns_warns(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("first", types::integer());
+                t.add_column("last", types::integer());
+                t.add_constraint("User_pkey", types::primary_constraint(&["last", "first"]));
+            });
+        })
+        .await?;
+
+    let input_dm = api.dm_with_sources(
+        r#"
+         model User {
+             first  Int
+             last   Int
+
+             @@id([first, last])
+         }
+     "#,
+    );
+
+    let final_dm = r#"
+         model User {
+             first  Int
+             last   Int
+
+             @@id([last, first])
+         }
+     "#;
+
+    let re_introspected = api.re_introspect(&input_dm).await?;
+
+    api.assert_eq_datamodels(final_dm, &re_introspected);
+
+    let expected = json!([{
+        "code": 24,
+        "message": "The order of the fields in the compound id field of these models was changed to match the order in the database. Consider keeping the previous order if it is important for query parameter order or pattern matching.",
+        "affected" :[
+            {"model": "User"}
+        ]
+    }]);
+
+    assert_eq_json!(expected, api.re_introspect_warnings(&input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn re_introspected_datamodel_is_available_as_json(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "User" (
+            "id" INTEGER PRIMARY KEY,
+            "email" TEXT NOT NULL
+        );
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id    Int    @id
+            email String
+        }
+    "#};
+
+    let json = api.re_introspect_json(input_dm).await?;
+
+    assert!(json.contains(r#""name":"User""#));
+    assert!(json.contains(r#""name":"email""#));
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres12))]
 async fn re_introspecting_custom_index_order(api: &TestApi) -> TestResult {
     let schema_name = api.schema_name();
@@ -1924,3 +2475,458 @@ async fn re_introspecting_custom_index_order(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn re_introspecting_preserves_hash_index_type(api: &TestApi) -> TestResult {
+    let schema_name = api.schema_name();
+    let create_table = format!("CREATE TABLE \"{schema_name}\".\"A\" (id SERIAL PRIMARY KEY, a INTEGER NOT NULL)",);
+    let create_idx = format!("CREATE INDEX \"A_a_idx\" ON \"{schema_name}\".\"A\" USING HASH (a);",);
+
+    api.database().raw_cmd(&create_table).await?;
+    api.database().raw_cmd(&create_idx).await?;
+
+    let dm = indoc! {r#"
+         model A {
+           id Int @id
+           a  Int
+
+           @@index([a], type: Hash)
+         }
+    "#};
+
+    let input_dm = api.dm_with_sources(dm);
+    let input_dm = api.dm_with_generator_and_preview_flags(&input_dm);
+    let re_introspected = api.re_introspect_dml(&input_dm).await?;
+
+    let expected = expect![[r#"
+        model A {
+          id Int @id @default(autoincrement())
+          a  Int
+
+          @@index([a], type: Hash)
+        }
+    "#]];
+
+    expected.assert_eq(&re_introspected);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn range_type_columns_stay_unsupported_across_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Booking", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("validity int4range not null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Booking {
+            id       Int                      @id @default(autoincrement())
+            validity Unsupported("int4range")
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Booking {
+          id       Int                     @id @default(autoincrement())
+          validity Unsupported("int4range")
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn identity_columns_stay_autoincrement_across_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Foo", |t| {
+                t.inject_custom("id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Foo {
+            id Int @id @default(autoincrement())
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Foo {
+          id Int @id @default(autoincrement())
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn custom_virtual_relation_field_names_1_to_1_with_mapped_fk(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("user_id", types::integer().nullable(false).unique(true));
+                t.add_foreign_key(&["user_id"], "User", &["id"]);
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Post {
+            id               Int  @id @default(autoincrement())
+            c_user_id        Int  @unique @map("user_id")
+            custom_User      User @relation(fields: [c_user_id], references: [id])
+        }
+
+        model User {
+            id               Int   @id @default(autoincrement())
+            custom_Post      Post?
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Post {
+          id          Int  @id @default(autoincrement())
+          c_user_id   Int  @unique @map("user_id")
+          custom_User User @relation(fields: [c_user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+        }
+
+        model User {
+          id          Int   @id @default(autoincrement())
+          custom_Post Post?
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn network_type_columns_keep_their_native_type_across_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Device", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("address inet not null");
+                t.inject_custom("subnet cidr not null");
+                t.inject_custom("mac macaddr not null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Device {
+            id      Int    @id @default(autoincrement())
+            address String @db.Inet
+            subnet  String @db.Cidr
+            mac     String @db.MacAddr
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Device {
+          id      Int    @id @default(autoincrement())
+          address String @db.Inet
+          subnet  String @db.Cidr
+          mac     String @db.MacAddr
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(exclude(Sqlite, Mssql, Mysql, CockroachDb))]
+async fn custom_fk_constraint_names_are_kept_across_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::integer().increments(true).nullable(false));
+                t.add_constraint("User_pkey", types::primary_constraint(&["id"]));
+            });
+
+            migration.create_table("Post", move |t| {
+                t.add_column("id", types::integer().increments(true).nullable(false));
+                t.add_constraint("Post_pkey", types::primary_constraint(&["id"]));
+                t.add_column("user_id", types::integer().nullable(false));
+                t.add_index("Post_user_id_idx", types::index(&["user_id"]));
+                t.add_constraint(
+                    "CustomFKName",
+                    types::foreign_constraint(&["user_id"], "User", &["id"], None, None),
+                );
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Post {
+            id      Int  @id @default(autoincrement())
+            user_id Int
+            User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction, map: "CustomFKName")
+
+            @@index([user_id])
+        }
+
+        model User {
+            id   Int    @id @default(autoincrement())
+            Post Post[]
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Post {
+          id      Int  @id @default(autoincrement())
+          user_id Int
+          User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction, map: "CustomFKName")
+
+          @@index([user_id])
+        }
+
+        model User {
+          id   Int    @id @default(autoincrement())
+          Post Post[]
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn bigserial_with_a_custom_sequence_name_keeps_autoincrement_across_re_introspection(
+    api: &TestApi,
+) -> TestResult {
+    api.raw_cmd(
+        r#"
+            CREATE SEQUENCE "CustomBigSerialSeq";
+
+            CREATE TABLE "Counter" (
+                id    INTEGER PRIMARY KEY,
+                value BIGINT NOT NULL DEFAULT nextval('"CustomBigSerialSeq"'::regclass)
+            );
+
+            ALTER SEQUENCE "CustomBigSerialSeq" OWNED BY "Counter"."value";
+        "#,
+    )
+    .await;
+
+    let input_dm = indoc! {r#"
+        model Counter {
+            id    Int    @id
+            value BigInt @default(autoincrement())
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Counter {
+          id    Int    @id
+          value BigInt @default(autoincrement())
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Mysql))]
+async fn tinyint_1_and_tinyint_4_keep_their_distinct_mappings_across_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("is_active TINYINT(1) NOT NULL");
+                t.inject_custom("favorite_number TINYINT(4) NOT NULL");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id              Int     @id @default(autoincrement())
+            is_active       Boolean
+            favorite_number Int     @db.TinyInt
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model User {
+          id              Int     @id @default(autoincrement())
+          is_active       Boolean
+          favorite_number Int     @db.TinyInt
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn timestamp_and_timestamptz_columns_keep_their_distinct_native_types_across_re_introspection(
+    api: &TestApi,
+) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Event", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("without_tz timestamp(3) not null");
+                t.inject_custom("with_tz timestamptz(3) not null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Event {
+            id         Int      @id @default(autoincrement())
+            without_tz DateTime @db.Timestamp(3)
+            with_tz    DateTime @db.Timestamptz(3)
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Event {
+          id         Int      @id @default(autoincrement())
+          without_tz DateTime @db.Timestamp(3)
+          with_tz    DateTime @db.Timestamptz(3)
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn mismatched_native_type_is_replaced_and_warned_about(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("name varchar(20) not null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id   Int    @id @default(autoincrement())
+            name String @db.VarChar(10)
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model User {
+          id   Int    @id @default(autoincrement())
+          name String @db.VarChar(20)
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    let expected_warnings = json!([{
+        "code": 27,
+        "message": "Native type replaced due to mismatch",
+        "affected": [{
+            "model": "User",
+            "field": "name",
+            "previous": "VarChar(10)",
+            "current": "VarChar(20)"
+        }]
+    }]);
+
+    assert_eq_json!(expected_warnings, api.re_introspect_warnings(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn changed_scalar_default_is_used_and_warned_about(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("credits integer not null default 10");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id      Int @id @default(autoincrement())
+            credits Int @default(5)
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model User {
+          id      Int @id @default(autoincrement())
+          credits Int @default(10)
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    let expected_warnings = json!([{
+        "code": 31,
+        "message": "Default value changed from previous schema",
+        "affected": [{
+            "model": "User",
+            "field": "credits",
+            "previous": "5",
+            "current": "10"
+        }]
+    }]);
+
+    assert_eq_json!(expected_warnings, api.re_introspect_warnings(input_dm).await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn id_field_backed_by_a_unique_index_keeps_its_id_across_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.inject_custom("id Integer Not Null");
+                t.inject_custom("name Text Not Null");
+                t.add_index("User_id_key", types::index(vec!["id"]).unique(true));
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id   Int    @id
+            name String
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model User {
+          id   Int    @id
+          name String
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
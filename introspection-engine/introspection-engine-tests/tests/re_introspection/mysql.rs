@@ -228,6 +228,36 @@ async fn multiple_changed_relation_names_due_to_mapped_models(api: &TestApi) ->
     Ok(())
 }
 
+#[test_connector(tags(Mysql), exclude(Vitess))]
+async fn year_columns_keep_their_native_type_across_re_introspection(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Employee", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("hireYear year not null");
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model Employee {
+            id       Int @id @default(autoincrement())
+            hireYear Int @db.Year
+        }
+    "#};
+
+    let expected = expect![[r#"
+        model Employee {
+          id       Int @id @default(autoincrement())
+          hireYear Int @db.Year
+        }
+    "#]];
+
+    expected.assert_eq(&api.re_introspect_dml(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Mysql))]
 async fn mysql_keeps_renamed_enum_defaults(api: &TestApi) -> TestResult {
     let init = formatdoc! {r#"
@@ -195,6 +195,65 @@ async fn compound_foreign_keys_for_one_to_many_relations_with_mixed_requiredness
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn compound_foreign_keys_with_three_columns_referencing_a_composite_unique(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("User", move |t| {
+                t.add_column("id", types::primary());
+                t.add_column("age", types::integer());
+                t.add_column("country", types::text());
+
+                t.add_constraint(
+                    "user_unique",
+                    types::unique_constraint(vec!["id", "age", "country"]),
+                );
+            });
+
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("user_id", types::integer());
+                t.add_column("user_age", types::integer());
+                t.add_column("user_country", types::text());
+
+                t.add_constraint(
+                    "Post_user_fkey",
+                    types::foreign_constraint(
+                        &["user_id", "user_age", "user_country"],
+                        "User",
+                        &["id", "age", "country"],
+                        None,
+                        None,
+                    ),
+                );
+            });
+        })
+        .await?;
+
+    let expected = expect![[r#"
+        model Post {
+          id           Int    @id @default(autoincrement())
+          user_id      Int
+          user_age     Int
+          user_country String
+          User         User   @relation(fields: [user_id, user_age, user_country], references: [id, age, country], onDelete: NoAction, onUpdate: NoAction, map: "Post_user_fkey")
+        }
+
+        model User {
+          id      Int    @id @default(autoincrement())
+          age     Int
+          country String
+          Post    Post[]
+
+          @@unique([id, age, country], map: "user_unique")
+        }
+    "#]];
+
+    expected.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 async fn compound_foreign_keys_with_defaults(api: &TestApi) -> TestResult {
     api.raw_cmd(r#"
@@ -279,6 +279,92 @@ async fn datetime_default_expressions_are_not_truncated(api: &TestApi) -> TestRe
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn uuid_columns_with_gen_random_uuid_default_are_dbgenerated(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "Foo" (
+            "id" UUID NOT NULL DEFAULT gen_random_uuid(),
+
+            CONSTRAINT "Foo_pkey" PRIMARY KEY ("id")
+        );
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expected = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        model Foo {
+          id String @id @default(dbgenerated("gen_random_uuid()")) @db.Uuid
+        }
+    "#]];
+
+    api.expect_datamodel(&expected).await;
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn identity_columns_are_mapped_to_autoincrement(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "Foo" (
+            "id" INTEGER GENERATED ALWAYS AS IDENTITY,
+
+            CONSTRAINT "Foo_pkey" PRIMARY KEY ("id")
+        );
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expected = expect![[r#"
+        model Foo {
+          id Int @id @default(autoincrement())
+        }
+    "#]];
+
+    expected.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn table_comments_become_model_documentation(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "Cat" (
+            "id" INTEGER PRIMARY KEY
+        );
+
+        COMMENT ON TABLE "Cat" IS 'This table stores cats.';
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expected = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        /// This table stores cats.
+        model Cat {
+          id Int @id
+        }
+    "#]];
+
+    api.expect_datamodel(&expected).await;
+    Ok(())
+}
+
 #[test_connector(tags(Postgres12, Postgres14), exclude(CockroachDb))]
 async fn northwind(api: TestApi) {
     let setup = include_str!("./northwind_postgresql.sql");
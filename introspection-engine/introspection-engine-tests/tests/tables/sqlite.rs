@@ -57,6 +57,85 @@ async fn a_table_with_descending_compound_unique(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Sqlite))]
+async fn a_without_rowid_table_with_compound_integer_pk_has_no_extra_id(api: &TestApi) -> TestResult {
+    let setup = indoc! {r#"
+       CREATE TABLE "A" (
+           a INTEGER NOT NULL,
+           b INTEGER NOT NULL,
+           PRIMARY KEY (a, b)
+       ) WITHOUT ROWID;
+   "#};
+
+    api.raw_cmd(setup).await;
+
+    let expectation = expect![[r#"
+        model A {
+          a Int
+          b Int
+
+          @@id([a, b])
+        }
+    "#]];
+
+    expectation.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Sqlite))]
+async fn a_without_rowid_table_with_single_integer_pk_is_not_autoincrement(api: &TestApi) -> TestResult {
+    let setup = indoc! {r#"
+       CREATE TABLE "A" (
+           id INTEGER NOT NULL,
+           a  INTEGER NOT NULL,
+           PRIMARY KEY (id)
+       ) WITHOUT ROWID;
+   "#};
+
+    api.raw_cmd(setup).await;
+
+    let expectation = expect![[r#"
+        model A {
+          id Int @id
+          a  Int
+        }
+    "#]];
+
+    expectation.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Sqlite))]
+async fn a_strict_table_keeps_its_exact_column_types(api: &TestApi) -> TestResult {
+    let setup = indoc! {r#"
+       CREATE TABLE "A" (
+           id        INTEGER NOT NULL PRIMARY KEY,
+           some_int  INTEGER NOT NULL,
+           some_text TEXT NOT NULL,
+           some_real REAL NOT NULL,
+           some_blob BLOB NOT NULL
+       ) STRICT;
+   "#};
+
+    api.raw_cmd(setup).await;
+
+    let expectation = expect![[r#"
+        model A {
+          id        Int    @id @default(autoincrement())
+          some_int  Int
+          some_text String
+          some_real Float
+          some_blob Bytes
+        }
+    "#]];
+
+    expectation.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Sqlite))]
 async fn a_table_with_descending_index(api: &TestApi) -> TestResult {
     let setup = indoc! {r#"
@@ -3,14 +3,15 @@
 use crate::{
     default_value::DefaultValue, field::FieldArity, native_type_instance::NativeTypeInstance, scalars::ScalarType,
 };
+use serde::Serialize;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct CompositeType {
     pub name: String,
     pub fields: Vec<CompositeTypeField>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct CompositeTypeField {
     pub name: String,
     pub r#type: CompositeTypeFieldType,
@@ -64,7 +65,7 @@ impl CompositeType {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub enum CompositeTypeFieldType {
     CompositeType(String),
     Scalar(ScalarType, Option<NativeTypeInstance>),
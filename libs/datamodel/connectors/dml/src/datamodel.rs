@@ -3,6 +3,7 @@ use crate::field::{Field, RelationField, ScalarField};
 use crate::model::Model;
 use crate::r#enum::Enum;
 use crate::relation_info::RelationInfo;
+use serde::Serialize;
 
 /// Entities in the datamodel can be flagged as `is_commented_out`. This lets the renderer
 /// know that introspection encountered unsupported names or features and these are supposed
@@ -10,7 +11,7 @@ use crate::relation_info::RelationInfo;
 /// string, only introspection and the lowering of the datamodel to the ast care about these flags.
 /// The FieldType: Unsupported behaves in the same way.
 /// Both of these are never converted into the internal datamodel.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct Datamodel {
     pub enums: Vec<Enum>,
     pub models: Vec<Model>,
@@ -1,16 +1,17 @@
 use crate::scalars::ScalarType;
 use prisma_value::PrismaValue;
+use serde::Serialize;
 use std::fmt;
 
 /// Represents a default specified on a field.
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Serialize)]
 pub struct DefaultValue {
     pub kind: DefaultKind,
     pub db_name: Option<String>,
 }
 
 /// Represents a default specified on a field.
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Serialize)]
 pub enum DefaultKind {
     /// a static value, e.g. `@default(1)`
     Single(PrismaValue),
@@ -145,7 +146,7 @@ impl DefaultValue {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize)]
 pub struct ValueGenerator {
     name: String,
     args: Vec<(Option<String>, PrismaValue)>,
@@ -237,7 +238,7 @@ impl ValueGenerator {
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Serialize)]
 pub enum ValueGeneratorFn {
     Uuid,
     Cuid,
@@ -1,7 +1,8 @@
 use crate::traits::{WithDatabaseName, WithName};
+use serde::Serialize;
 
 /// Represents an enum in the datamodel.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct Enum {
     /// Name of the enum.
     pub name: String,
@@ -87,7 +88,7 @@ impl WithDatabaseName for Enum {
 }
 
 /// Represents a value of an enum
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct EnumValue {
     /// Value as exposed by the api
     pub name: String,
@@ -1,6 +1,7 @@
 //! A field in a model.
 
 use crate::native_type_instance::NativeTypeInstance;
+use serde::Serialize;
 use crate::relation_info::RelationInfo;
 use crate::scalars::ScalarType;
 use crate::traits::{Ignorable, WithDatabaseName, WithName};
@@ -11,7 +12,7 @@ use crate::{
 use std::hash::Hash;
 
 /// Arity of a Field in a Model.
-#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash, Serialize)]
 pub enum FieldArity {
     Required,
     Optional,
@@ -33,7 +34,7 @@ impl FieldArity {
 }
 
 /// Datamodel field type.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub enum FieldType {
     /// This is an enum field, with an enum of the given name.
     Enum(String),
@@ -100,7 +101,7 @@ impl FieldType {
 }
 
 /// Represents a Field in a Model.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub enum Field {
     ScalarField(ScalarField),
     RelationField(RelationField),
@@ -278,7 +279,7 @@ impl Ignorable for Field {
 }
 
 /// Represents a relation field in a model.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct RelationField {
     /// Name of the field.
     pub name: String,
@@ -400,7 +401,7 @@ impl WithName for RelationField {
 }
 
 /// Represents a scalar field in a model.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct ScalarField {
     /// Name of the field.
     pub name: String,
@@ -513,7 +514,7 @@ impl WithDatabaseName for ScalarField {
 }
 
 /// Represents a composite field.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct CompositeField {
     /// Name of the field.
     pub name: String,
@@ -1,4 +1,5 @@
 use crate::default_value::DefaultKind;
+use serde::Serialize;
 use crate::field::{Field, FieldType, RelationField, ScalarField};
 use crate::scalars::ScalarType;
 use crate::traits::{Ignorable, WithDatabaseName, WithName};
@@ -6,7 +7,7 @@ use indoc::formatdoc;
 use std::{borrow::Cow, fmt};
 
 /// Represents a model in a prisma schema.
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
 pub struct Model {
     /// Name of the model.
     pub name: String,
@@ -30,7 +31,7 @@ pub struct Model {
     pub schema: Option<String>,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub enum IndexAlgorithm {
     BTree,
     Hash,
@@ -60,7 +61,7 @@ impl fmt::Display for IndexAlgorithm {
 }
 
 /// Represents an index defined via `@@index`, `@unique` or `@@unique`.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct IndexDefinition {
     pub name: Option<String>,
     pub db_name: Option<String>,
@@ -81,7 +82,7 @@ impl IndexDefinition {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub enum OperatorClass {
     // GiST
     InetOps,
@@ -232,7 +233,7 @@ impl OperatorClass {
 }
 
 ///A field in an index that optionally defines a sort order and length limit.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct IndexField {
     pub path: Vec<(String, Option<String>)>,
     pub sort_order: Option<SortOrder>,
@@ -265,7 +266,7 @@ impl IndexField {
 }
 
 /// Represents a primary key defined via `@@id` or `@id`.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct PrimaryKeyDefinition {
     pub name: Option<String>,
     pub db_name: Option<String>,
@@ -275,7 +276,7 @@ pub struct PrimaryKeyDefinition {
 }
 
 ///A field in a Primary Key that optionally defines a sort order and length limit.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct PrimaryKeyField {
     pub name: String,
     pub sort_order: Option<SortOrder>,
@@ -293,7 +294,7 @@ impl PrimaryKeyField {
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub enum IndexType {
     Unique,
     Normal,
@@ -306,7 +307,7 @@ impl IndexType {
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub enum SortOrder {
     Asc,
     Desc,
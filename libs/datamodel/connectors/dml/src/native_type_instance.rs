@@ -1,8 +1,9 @@
 use native_types::NativeType;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 /// represents an instance of a native type declared in the Prisma schema
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct NativeTypeInstance {
     /// the name of the native type used in the Prisma schema
     pub name: String,
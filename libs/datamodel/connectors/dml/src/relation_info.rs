@@ -1,8 +1,9 @@
 use enumflags2::bitflags;
+use serde::Serialize;
 use std::fmt;
 
 /// Holds information about a relation field.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct RelationInfo {
     /// The target model of the relation.
     pub to: String,
@@ -41,7 +42,7 @@ impl RelationInfo {
 /// Describes what happens when related nodes are deleted.
 #[repr(u8)]
 #[bitflags]
-#[derive(Debug, Copy, PartialEq, Clone)]
+#[derive(Debug, Copy, PartialEq, Clone, Serialize)]
 pub enum ReferentialAction {
     /// Deletes record if dependent record is deleted. Updates relation scalar
     /// fields if referenced scalar fields of the dependent record are updated.
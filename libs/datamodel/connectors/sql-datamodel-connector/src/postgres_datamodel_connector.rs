@@ -23,6 +23,8 @@ const BIG_INT_TYPE_NAME: &str = "BigInt";
 const DECIMAL_TYPE_NAME: &str = "Decimal";
 const MONEY_TYPE_NAME: &str = "Money";
 const INET_TYPE_NAME: &str = "Inet";
+const CIDR_TYPE_NAME: &str = "Cidr";
+const MAC_ADDR_TYPE_NAME: &str = "MacAddr";
 const CITEXT_TYPE_NAME: &str = "Citext";
 const OID_TYPE_NAME: &str = "Oid";
 const REAL_TYPE_NAME: &str = "Real";
@@ -51,6 +53,8 @@ const NATIVE_TYPE_CONSTRUCTORS: &[NativeTypeConstructor] = &[
     NativeTypeConstructor::with_optional_args(DECIMAL_TYPE_NAME, 2, &[ScalarType::Decimal]),
     NativeTypeConstructor::without_args(MONEY_TYPE_NAME, &[ScalarType::Decimal]),
     NativeTypeConstructor::without_args(INET_TYPE_NAME, &[ScalarType::String]),
+    NativeTypeConstructor::without_args(CIDR_TYPE_NAME, &[ScalarType::String]),
+    NativeTypeConstructor::without_args(MAC_ADDR_TYPE_NAME, &[ScalarType::String]),
     NativeTypeConstructor::without_args(CITEXT_TYPE_NAME, &[ScalarType::String]),
     NativeTypeConstructor::without_args(OID_TYPE_NAME, &[ScalarType::Int]),
     NativeTypeConstructor::without_args(REAL_TYPE_NAME, &[ScalarType::Float]),
@@ -173,6 +177,8 @@ impl Connector for PostgresDatamodelConnector {
             Uuid => ScalarType::String,
             Xml => ScalarType::String,
             Inet => ScalarType::String,
+            Cidr => ScalarType::String,
+            MacAddr => ScalarType::String,
             Citext => ScalarType::String,
             //Boolean
             Boolean => ScalarType::Boolean,
@@ -292,6 +298,8 @@ impl Connector for PostgresDatamodelConnector {
             BIG_INT_TYPE_NAME => BigInt,
             DECIMAL_TYPE_NAME => Decimal(parse_two_opt_u32(args, DECIMAL_TYPE_NAME, span)?),
             INET_TYPE_NAME => Inet,
+            CIDR_TYPE_NAME => Cidr,
+            MAC_ADDR_TYPE_NAME => MacAddr,
             MONEY_TYPE_NAME => Money,
             CITEXT_TYPE_NAME => Citext,
             OID_TYPE_NAME => Oid,
@@ -346,6 +354,8 @@ impl Connector for PostgresDatamodelConnector {
             JsonB => (JSON_B_TYPE_NAME, vec![]),
             Money => (MONEY_TYPE_NAME, vec![]),
             Inet => (INET_TYPE_NAME, vec![]),
+            Cidr => (CIDR_TYPE_NAME, vec![]),
+            MacAddr => (MAC_ADDR_TYPE_NAME, vec![]),
             Citext => (CITEXT_TYPE_NAME, vec![]),
             Oid => (OID_TYPE_NAME, vec![]),
         };
@@ -9,6 +9,8 @@ pub enum PostgresType {
     Decimal(Option<(u32, u32)>),
     Money,
     Inet,
+    Cidr,
+    MacAddr,
     Oid,
     Citext,
     Real,
@@ -40,6 +40,11 @@ pub trait SqlSchemaDescriberBackend: Send + Sync {
     async fn get_metadata(&self, schema: &str) -> DescriberResult<SqlMetadata>;
 
     /// Describe a database schema.
+    ///
+    /// Only a single schema is described per call today; the resulting `SqlSchema` records it as
+    /// its sole namespace (see `SqlSchema::push_namespace` and `Table::namespace_id`). Describing
+    /// several schemas at once to support Postgres' `multiSchema` style setups would mean widening
+    /// this to a list of schema names, one `push_namespace` per name.
     async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema>;
 
     /// Get the database version.
@@ -221,10 +226,19 @@ impl SqlSchema {
 
     pub fn push_table(&mut self, name: String, namespace_id: NamespaceId) -> TableId {
         let id = TableId(self.tables.len() as u32);
-        self.tables.push(Table { namespace_id, name });
+        self.tables.push(Table {
+            namespace_id,
+            name,
+            description: None,
+        });
         id
     }
 
+    /// Set the database-level comment/description of a table, if the connector supports it.
+    pub fn set_table_description(&mut self, table_id: TableId, description: String) {
+        self.tables[table_id.0 as usize].description = Some(description);
+    }
+
     pub fn tables_count(&self) -> usize {
         self.tables.len()
     }
@@ -284,6 +298,7 @@ impl SqlSchema {
 pub struct Table {
     namespace_id: NamespaceId,
     name: String,
+    description: Option<String>,
 }
 
 /// The type of an index.
@@ -371,6 +386,11 @@ pub struct Column {
     pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// Is the column a generated/computed column whose value is derived from other columns?
+    pub generated: bool,
+    /// Does the column automatically update to the current timestamp when its row is updated
+    /// (MySQL's column-level `ON UPDATE CURRENT_TIMESTAMP`)?
+    pub auto_updates_to_now: bool,
 }
 
 /// The type of a column.
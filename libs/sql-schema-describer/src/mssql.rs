@@ -249,6 +249,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 OBJECT_DEFINITION(c.default_object_id)                          AS column_default,
                 c.is_nullable                                                   AS is_nullable,
                 COLUMNPROPERTY(c.object_id, c.name, 'IsIdentity')               AS is_identity,
+                c.is_computed                                                   AS is_computed,
                 OBJECT_NAME(c.object_id)                                        AS table_name,
                 OBJECT_NAME(c.default_object_id)                                AS constraint_name,
                 convert(tinyint, CASE
@@ -299,6 +300,8 @@ impl<'a> SqlSchemaDescriber<'a> {
             );
 
             let auto_increment = col.get_expect_bool("is_identity");
+            // `AS (...)` computed columns report `is_computed = 1`.
+            let generated = col.get_expect_bool("is_computed");
 
             let default = match col.get("column_default") {
                 None => None,
@@ -367,6 +370,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                     tpe,
                     default,
                     auto_increment,
+                    generated,
+                    auto_updates_to_now: false,
                 },
             ));
         }
@@ -262,7 +262,7 @@ impl<'a> SqlSchemaDescriber<'a> {
     ) -> DescriberResult<IndexMap<String, TableId>> {
         // Only consider tables for which we can read at least one column.
         let sql = r#"
-            SELECT DISTINCT BINARY table_info.table_name AS table_name
+            SELECT DISTINCT BINARY table_info.table_name AS table_name, NULLIF(table_info.table_comment, '') AS table_comment
             FROM information_schema.tables AS table_info
             JOIN information_schema.columns AS column_info
                 ON BINARY column_info.table_name = BINARY table_info.table_name
@@ -273,13 +273,19 @@ impl<'a> SqlSchemaDescriber<'a> {
                 AND table_info.table_type = 'BASE TABLE'
             ORDER BY BINARY table_info.table_name"#;
         let rows = self.conn.query_raw(sql, &[schema.into(), schema.into()]).await?;
-        let names = rows.into_iter().map(|row| row.get_expect_string("table_name"));
 
         let mut map = IndexMap::default();
 
-        for name in names {
+        for row in rows.into_iter() {
+            let name = row.get_expect_string("table_name");
+            let comment = row.get_string("table_comment");
             let cloned_name = name.clone();
             let id = sql_schema.push_table(name, Default::default());
+
+            if let Some(comment) = comment {
+                sql_schema.set_table_description(id, comment);
+            }
+
             map.insert(cloned_name, id);
         }
 
@@ -489,11 +495,15 @@ impl<'a> SqlSchemaDescriber<'a> {
                 },
             };
 
+            let auto_updates_to_now = extra.contains("on update");
+
             let col = Column {
                 name,
                 tpe,
                 default,
                 auto_increment,
+                generated: false,
+                auto_updates_to_now,
             };
 
             sql_schema.columns.push((table_id, col));
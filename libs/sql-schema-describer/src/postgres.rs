@@ -11,7 +11,11 @@ use indoc::indoc;
 use native_types::{CockroachType, NativeType, PostgresType};
 use quaint::{connector::ResultRow, prelude::Queryable};
 use regex::Regex;
-use std::{any::type_name, collections::BTreeMap, convert::TryInto};
+use std::{
+    any::type_name,
+    collections::{BTreeMap, HashMap},
+    convert::TryInto,
+};
 use tracing::trace;
 
 /// A PostgreSQL sequence.
@@ -114,6 +118,15 @@ pub struct PostgresSchemaExt {
     pub indexes: Vec<(IndexId, SqlIndexAlgorithm)>,
     /// The schema's sequences.
     pub sequences: Vec<Sequence>,
+    /// (table, constraint name) for every CHECK constraint found in the schema. Prisma does not
+    /// model check constraints, so this is informational only.
+    pub check_constraints: Vec<(TableId, String)>,
+    /// (domain name, base type name) for every `CREATE DOMAIN` type found in the schema. Used to
+    /// tell whether a column's `full_data_type` is a domain that was resolved to its base type.
+    pub domains: Vec<(String, String)>,
+    /// (table, constraint name) for every EXCLUDE constraint found in the schema. Prisma does not
+    /// model exclusion constraints, so this is informational only.
+    pub exclusion_constraints: Vec<(TableId, String)>,
 }
 
 impl PostgresSchemaExt {
@@ -139,6 +152,12 @@ impl PostgresSchemaExt {
             .map(|idx| (idx, &self.sequences[idx]))
             .ok()
     }
+
+    /// Whether `full_data_type` is the name of a domain found in the schema, i.e. whether a
+    /// column reporting it as its `udt_name` was declared with a domain type.
+    pub fn is_domain(&self, full_data_type: &str) -> bool {
+        self.domains.iter().any(|(name, _)| name == full_data_type)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -408,7 +427,8 @@ impl<'a> super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'a> {
 
     async fn get_metadata(&self, schema: &str) -> DescriberResult<SqlMetadata> {
         let mut sql_schema = SqlSchema::default();
-        let table_count = self.get_table_names(schema, &mut sql_schema).await?.len();
+        let namespace_id = sql_schema.push_namespace(schema.to_owned());
+        let table_count = self.get_table_names(schema, namespace_id, &mut sql_schema).await?.len();
         let size_in_bytes = self.get_size(schema).await?;
 
         Ok(SqlMetadata {
@@ -420,21 +440,34 @@ impl<'a> super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'a> {
     async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
         let mut sql_schema = SqlSchema::default();
         let mut pg_ext = PostgresSchemaExt::default();
-        let table_names = self.get_table_names(schema, &mut sql_schema).await?;
+        // Only a single schema is described today, so it is also the only namespace. Describing
+        // several schemas in one pass (see the cross-schema foreign key naming in
+        // `get_foreign_keys` below) would mean calling `push_namespace` once per requested schema
+        // and passing the right `NamespaceId` down to `get_table_names` for each of them.
+        let namespace_id = sql_schema.push_namespace(schema.to_owned());
+        let table_names = self.get_table_names(schema, namespace_id, &mut sql_schema).await?;
 
         self.get_sequences(schema, &mut pg_ext).await?;
         sql_schema.enums = self.get_enums(schema).await?;
-        self.get_columns(schema, &table_names, &mut sql_schema).await?;
+        let domains = self.get_domains(schema).await?;
+        self.get_columns(schema, &table_names, &domains, &mut sql_schema).await?;
         self.get_foreign_keys(schema, &table_names, &mut sql_schema).await?;
         self.get_indices(schema, &table_names, &mut pg_ext, &mut sql_schema)
             .await?;
+        self.get_check_constraints(schema, &table_names, &mut pg_ext).await?;
+        self.get_exclusion_constraints(schema, &table_names, &mut pg_ext).await?;
 
         sql_schema.views = self.get_views(schema).await?;
         sql_schema.procedures = self.get_procedures(schema).await?;
 
+        pg_ext.domains = domains.into_iter().collect();
+
         // Make sure the vectors we use binary search on are sorted.
         pg_ext.indexes.sort_by_key(|(id, _)| *id);
         pg_ext.opclasses.sort_by_key(|(id, _)| *id);
+        pg_ext.check_constraints.sort_by_key(|(id, _)| *id);
+        pg_ext.exclusion_constraints.sort_by_key(|(id, _)| *id);
+        pg_ext.domains.sort();
 
         sql_schema.connector_data = crate::connector_data::ConnectorData {
             data: Some(Box::new(pg_ext)),
@@ -502,17 +535,24 @@ impl<'a> SqlSchemaDescriber<'a> {
     async fn get_table_names(
         &self,
         schema: &str,
+        namespace_id: NamespaceId,
         sql_schema: &mut SqlSchema,
     ) -> DescriberResult<IndexMap<String, TableId>> {
         let sql = include_str!("postgres/tables_query.sql");
 
         let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
-        let names = rows.into_iter().map(|row| row.get_expect_string("table_name"));
         let mut map = IndexMap::default();
 
-        for name in names {
+        for row in rows.into_iter() {
+            let name = row.get_expect_string("table_name");
+            let description = row.get_string("description");
             let cloned_name = name.clone();
-            let id = sql_schema.push_table(name, Default::default());
+            let id = sql_schema.push_table(name, namespace_id);
+
+            if let Some(description) = description {
+                sql_schema.set_table_description(id, description);
+            }
+
             map.insert(cloned_name, id);
         }
 
@@ -560,6 +600,7 @@ impl<'a> SqlSchemaDescriber<'a> {
         &self,
         schema: &str,
         table_ids: &IndexMap<String, TableId>,
+        domains: &HashMap<String, String>,
         sql_schema: &mut SqlSchema,
     ) -> DescriberResult<()> {
         let is_visible_clause = if self.is_cockroach() {
@@ -583,7 +624,9 @@ impl<'a> SqlSchemaDescriber<'a> {
                 pg_get_expr(attdef.adbin, attdef.adrelid) AS column_default,
                 info.is_nullable,
                 info.is_identity,
-                info.character_maximum_length
+                info.is_generated,
+                info.character_maximum_length,
+                att.attndims as array_dimensions
             FROM information_schema.columns info
             JOIN pg_attribute att ON att.attname = info.column_name
                 AND att.attrelid = (
@@ -617,6 +660,10 @@ impl<'a> SqlSchemaDescriber<'a> {
                 None => false,
             };
 
+            // `GENERATED ALWAYS AS (...) STORED` columns report `is_generated = 'ALWAYS'`; regular
+            // columns (including those with a plain default) report `'NEVER'`.
+            let is_generated = matches!(col.get_string("is_generated"), Some(is_gen) if is_gen.eq_ignore_ascii_case("always"));
+
             let tpe = if self.is_cockroach()
                 && !self
                     .circumstances
@@ -624,7 +671,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             {
                 get_column_type_cockroachdb(&col, &sql_schema.enums)
             } else {
-                get_column_type_postgresql(&col, &sql_schema.enums)
+                get_column_type_postgresql(&col, &sql_schema.enums, domains)
             };
             let default = col
                 .get("column_default")
@@ -644,6 +691,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                 tpe,
                 default,
                 auto_increment,
+                generated: is_generated,
+                auto_updates_to_now: false,
             };
 
             sql_schema.columns.push((*table_id, col));
@@ -1012,6 +1061,74 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(())
     }
 
+    async fn get_check_constraints(
+        &self,
+        schema: &str,
+        table_ids: &IndexMap<String, TableId>,
+        postgres_ext: &mut PostgresSchemaExt,
+    ) -> DescriberResult<()> {
+        // contype = 'c' selects CHECK constraints specifically, as opposed to primary keys,
+        // foreign keys and unique constraints which pg_constraint also stores.
+        let sql = r#"
+            SELECT con.conname AS constraint_name, rel.relname AS table_name
+            FROM pg_constraint con
+            JOIN pg_class rel ON rel.oid = con.conrelid
+            JOIN pg_namespace nsp ON nsp.oid = rel.relnamespace
+            WHERE con.contype = 'c'
+            AND nsp.nspname = $1
+            ORDER BY table_name, constraint_name
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let table_id = match table_ids.get(&table_name) {
+                Some(table_id) => *table_id,
+                None => continue,
+            };
+            let constraint_name = row.get_expect_string("constraint_name");
+
+            postgres_ext.check_constraints.push((table_id, constraint_name));
+        }
+
+        Ok(())
+    }
+
+    async fn get_exclusion_constraints(
+        &self,
+        schema: &str,
+        table_ids: &IndexMap<String, TableId>,
+        postgres_ext: &mut PostgresSchemaExt,
+    ) -> DescriberResult<()> {
+        // contype = 'x' selects EXCLUDE constraints specifically. Their backing index is filtered
+        // out of `indexes_query.sql`, so this is the only place they are described.
+        let sql = r#"
+            SELECT con.conname AS constraint_name, rel.relname AS table_name
+            FROM pg_constraint con
+            JOIN pg_class rel ON rel.oid = con.conrelid
+            JOIN pg_namespace nsp ON nsp.oid = rel.relnamespace
+            WHERE con.contype = 'x'
+            AND nsp.nspname = $1
+            ORDER BY table_name, constraint_name
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let table_id = match table_ids.get(&table_name) {
+                Some(table_id) => *table_id,
+                None => continue,
+            };
+            let constraint_name = row.get_expect_string("constraint_name");
+
+            postgres_ext.exclusion_constraints.push((table_id, constraint_name));
+        }
+
+        Ok(())
+    }
+
     async fn get_enums(&self, schema: &str) -> DescriberResult<Vec<Enum>> {
         let sql = "
             SELECT t.typname as name, e.enumlabel as value
@@ -1046,9 +1163,29 @@ impl<'a> SqlSchemaDescriber<'a> {
 
         Ok(enums)
     }
+
+    /// Maps each domain's own type name (`udt_name` as reported by `information_schema.columns`
+    /// for a domain-typed column) to the `udt_name` of its base type, so domain columns can be
+    /// introspected as if they were declared with the base type directly.
+    async fn get_domains(&self, schema: &str) -> DescriberResult<HashMap<String, String>> {
+        let sql = "
+            SELECT domain_type.typname AS domain_name, base_type.typname AS base_type_name
+            FROM pg_type domain_type
+            JOIN pg_type base_type ON base_type.oid = domain_type.typbasetype
+            JOIN pg_namespace namespace ON namespace.oid = domain_type.typnamespace
+            WHERE domain_type.typtype = 'd'
+            AND namespace.nspname = $1";
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.get_expect_string("domain_name"), row.get_expect_string("base_type_name")))
+            .collect())
+    }
 }
 
-fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum]) -> ColumnType {
+fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum], domains: &HashMap<String, String>) -> ColumnType {
     use ColumnTypeFamily::*;
     let data_type = row.get_expect_string("data_type");
     let full_data_type = row.get_expect_string("full_data_type");
@@ -1068,7 +1205,27 @@ fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum]) -> ColumnType {
     let unsupported_type = || (Unsupported(full_data_type.clone()), None);
     let enum_exists = |name| enums.iter().any(|e| e.name == name);
 
-    let (family, native_type) = match full_data_type.as_str() {
+    // Postgres lets you declare `int[][]`, but it doesn't actually create a distinct type: the
+    // column is still stored as a one-dimensional `_int4` array and `attndims` is purely
+    // advisory. Prisma only supports one-dimensional arrays, so a declared dimension greater
+    // than 1 is surfaced as `Unsupported` instead of being silently treated as a normal list.
+    let is_multi_dimensional_array = data_type == "ARRAY" && row.get_expect_i64("array_dimensions") > 1;
+
+    if is_multi_dimensional_array {
+        return ColumnType {
+            family: Unsupported(format!("{}[]", full_data_type)),
+            full_data_type,
+            arity,
+            native_type: None,
+        };
+    }
+
+    // `full_data_type` (the column's `udt_name`) is the domain's own name for a domain-typed
+    // column, not its base type's name. Resolve it so the match below classifies the column as
+    // if it had been declared with the base type directly.
+    let resolved_type = domains.get(&full_data_type).unwrap_or(&full_data_type);
+
+    let (family, native_type) = match resolved_type.as_str() {
         name if data_type == "USER-DEFINED" && enum_exists(name) => (Enum(name.to_owned()), None),
         name if data_type == "ARRAY" && name.starts_with('_') && enum_exists(name.trim_start_matches('_')) => {
             (Enum(name.trim_start_matches('_').to_owned()), None)
@@ -1092,6 +1249,9 @@ fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         "jsonb" | "_jsonb" => (Json, Some(PostgresType::JsonB)),
         "uuid" | "_uuid" => (Uuid, Some(PostgresType::Uuid)),
         "xml" | "_xml" => (String, Some(PostgresType::Xml)),
+        // The `hstore` contrib extension type has no JSON-like native type of its own, so it is
+        // mapped to `Json` as a lossy (but queryable) approximation.
+        "hstore" | "_hstore" => (Json, None),
         // bit and varbit should be binary, but are currently mapped to strings.
         "bit" | "_bit" => (String, Some(PostgresType::Bit(precision.character_maximum_length))),
         "varbit" | "_varbit" => (String, Some(PostgresType::VarBit(precision.character_maximum_length))),
@@ -1115,6 +1275,8 @@ fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         "tsvector" | "_tsvector" => unsupported_type(),
         "txid_snapshot" | "_txid_snapshot" => unsupported_type(),
         "inet" | "_inet" => (String, Some(PostgresType::Inet)),
+        "cidr" | "_cidr" => (String, Some(PostgresType::Cidr)),
+        "macaddr" | "_macaddr" => (String, Some(PostgresType::MacAddr)),
         //geometric
         "box" | "_box" => unsupported_type(),
         "circle" | "_circle" => unsupported_type(),
@@ -696,6 +696,20 @@ mod tests {
         expected.assert_debug_eq(&out);
     }
 
+    #[test]
+    fn parse_bytea_default() {
+        let parsed_default = get_default_value(
+            r#"'\x00'::bytea"#,
+            &ColumnType::pure(ColumnTypeFamily::Binary, crate::ColumnArity::Required),
+        )
+        .unwrap();
+
+        match parsed_default.kind() {
+            DefaultKind::Value(PrismaValue::Bytes(bytes)) => assert_eq!(bytes, &[0u8]),
+            other => panic!("Expected a decoded bytea literal default, got: {:?}", other),
+        }
+    }
+
     #[test]
     fn postgres_is_sequence_works() {
         let assert_is_sequence = |default_str: &str, expected_sequence: &str| {
@@ -171,10 +171,28 @@ impl<'a> SqlSchemaDescriber<'a> {
     }
 
     async fn push_table(&self, name: &str, table_id: TableId, schema: &mut SqlSchema) -> DescriberResult<()> {
-        push_columns(name, table_id, schema, self.conn).await?;
+        let without_rowid = self.is_without_rowid(name).await?;
+        push_columns(name, table_id, schema, self.conn, without_rowid).await?;
         push_indexes(name, table_id, schema, self.conn).await
     }
 
+    /// `WITHOUT ROWID` tables don't alias an `INTEGER PRIMARY KEY` column to the hidden rowid, so
+    /// such a column is not autoincrementing the way it would be in a normal, rowid table.
+    /// See https://www.sqlite.org/withoutrowid.html.
+    async fn is_without_rowid(&self, table_name: &str) -> DescriberResult<bool> {
+        let sql = "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?";
+        let result_set = self.conn.query_raw(sql, &[table_name.into()]).await?;
+
+        let is_without_rowid = result_set
+            .into_single()
+            .ok()
+            .and_then(|row| row.get_string("sql"))
+            .map(|sql| sql.to_lowercase().trim_end().ends_with("without rowid"))
+            .unwrap_or(false);
+
+        Ok(is_without_rowid)
+    }
+
     async fn get_views(&self) -> DescriberResult<Vec<View>> {
         let sql = "SELECT name AS view_name, sql AS view_sql FROM sqlite_master WHERE type = 'view'";
         let result_set = self.conn.query_raw(sql, &[]).await?;
@@ -320,6 +338,7 @@ async fn push_columns(
     table_id: TableId,
     schema: &mut SqlSchema,
     conn: &(dyn Connection + Send + Sync),
+    without_rowid: bool,
 ) -> DescriberResult<()> {
     let sql = format!(r#"PRAGMA table_info ("{}")"#, table_name);
     let result_set = conn.query_raw(&sql, &[]).await?;
@@ -399,6 +418,8 @@ async fn push_columns(
                 tpe,
                 default,
                 auto_increment: false,
+                generated: false,
+                auto_updates_to_now: false,
             },
         );
 
@@ -418,8 +439,10 @@ async fn push_columns(
             });
         }
 
-        // Integer ID columns are always implemented with either row id or autoincrement
-        if pk_cols.len() == 1 {
+        // Integer ID columns are always implemented with either row id or autoincrement, unless the
+        // table was declared `WITHOUT ROWID`, in which case there is no hidden rowid for the column
+        // to alias and it behaves like a plain integer primary key instead.
+        if pk_cols.len() == 1 && !without_rowid {
             let pk_col_id = *pk_cols.values().next().unwrap();
             let pk_col = &mut schema.columns[pk_col_id.0 as usize];
             // See https://www.sqlite.org/lang_createtable.html for the exact logic.
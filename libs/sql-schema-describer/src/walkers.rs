@@ -139,6 +139,17 @@ impl<'a> ColumnWalker<'a> {
         self.get().1.auto_increment
     }
 
+    /// Is this column a generated/computed column whose value is derived from other columns?
+    pub fn is_generated(self) -> bool {
+        self.get().1.generated
+    }
+
+    /// Does this column automatically update to the current timestamp when its row is updated
+    /// (MySQL's column-level `ON UPDATE CURRENT_TIMESTAMP`)?
+    pub fn auto_updates_to_now(self) -> bool {
+        self.get().1.auto_updates_to_now
+    }
+
     /// Is this column indexed by a secondary index??
     pub fn is_part_of_secondary_index(self) -> bool {
         self.table().indexes().any(|idx| idx.contains_column(self.id))
@@ -265,6 +276,11 @@ impl<'a> TableWalker<'a> {
         &self.table().name
     }
 
+    /// The database-level comment/description attached to the table, if any.
+    pub fn description(self) -> Option<&'a str> {
+        self.table().description.as_deref()
+    }
+
     fn foreign_keys_range(self) -> Range<usize> {
         range_for_key(&self.schema.foreign_keys, self.id, |fk| fk.constrained_table)
     }
@@ -158,6 +158,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -178,6 +180,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -205,6 +209,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -225,6 +231,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -245,6 +253,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -272,6 +282,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -292,6 +304,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -312,6 +326,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -332,6 +348,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -352,6 +370,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -374,6 +394,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -394,6 +416,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -414,6 +438,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -434,6 +460,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -454,6 +482,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -474,6 +504,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -494,6 +526,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -516,6 +550,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -540,6 +576,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -562,6 +600,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -582,6 +622,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -606,6 +648,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -628,6 +672,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -648,6 +694,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -670,6 +718,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -694,6 +744,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -716,6 +768,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -736,6 +790,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -756,6 +812,8 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -131,6 +131,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -151,6 +153,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -171,6 +175,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -191,6 +197,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -211,6 +219,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -231,6 +241,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -251,6 +263,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -278,6 +292,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -305,6 +321,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -325,6 +343,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -345,6 +365,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -365,6 +387,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -387,6 +411,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -409,6 +435,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -436,6 +464,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -456,6 +486,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -478,6 +510,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -500,6 +534,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -520,6 +556,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -540,6 +578,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -560,6 +600,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -580,6 +622,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -598,6 +642,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -614,6 +660,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -636,6 +684,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -658,6 +708,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -678,6 +730,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -698,6 +752,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -718,6 +774,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -738,6 +796,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -756,6 +816,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -774,6 +836,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -792,6 +856,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -810,6 +876,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -828,6 +896,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -846,6 +916,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -864,6 +936,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -882,6 +956,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -902,6 +978,8 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -1028,6 +1106,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1048,6 +1128,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1068,6 +1150,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1088,6 +1172,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1108,6 +1194,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1128,6 +1216,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1148,6 +1238,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1175,6 +1267,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1202,6 +1296,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1222,6 +1318,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1242,6 +1340,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1262,6 +1362,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1284,6 +1386,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1306,6 +1410,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1333,6 +1439,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1353,6 +1461,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1375,6 +1485,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1397,6 +1509,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1417,6 +1531,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1437,6 +1553,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1457,6 +1575,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1477,6 +1597,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1495,6 +1617,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1511,6 +1635,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1533,6 +1659,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1555,6 +1683,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1575,6 +1705,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1595,6 +1727,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1615,6 +1749,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1635,6 +1771,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1653,6 +1791,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1671,6 +1811,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1689,6 +1831,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1707,6 +1851,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1725,6 +1871,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1743,6 +1891,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1761,6 +1911,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1779,6 +1931,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1799,6 +1953,8 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -1925,6 +2081,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1945,6 +2103,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1965,6 +2125,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1985,6 +2147,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2005,6 +2169,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2025,6 +2191,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2045,6 +2213,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2072,6 +2242,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2099,6 +2271,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2119,6 +2293,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2139,6 +2315,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2159,6 +2337,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2181,6 +2361,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2203,6 +2385,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2225,6 +2409,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2245,6 +2431,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2267,6 +2455,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2289,6 +2479,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2309,6 +2501,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2329,6 +2523,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2349,6 +2545,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2369,6 +2567,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2387,6 +2587,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2403,6 +2605,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2425,6 +2629,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2447,6 +2653,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2467,6 +2675,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2487,6 +2697,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2507,6 +2719,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2527,6 +2741,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2545,6 +2761,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2563,6 +2781,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2581,6 +2801,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2599,6 +2821,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2617,6 +2841,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2635,6 +2861,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2653,6 +2881,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2671,6 +2901,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2691,6 +2923,8 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -2917,6 +3151,8 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2939,6 +3175,8 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -2961,6 +3199,8 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -3113,6 +3353,8 @@ fn introspected_default_strings_should_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -3181,6 +3423,8 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3212,6 +3456,8 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -3280,6 +3526,8 @@ fn escaped_backslashes_in_string_literals_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -3365,6 +3613,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3392,6 +3642,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3419,6 +3671,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3453,6 +3707,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3480,6 +3736,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3509,6 +3767,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3536,6 +3796,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3565,6 +3827,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3594,6 +3858,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3621,6 +3887,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3646,6 +3914,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -3669,6 +3939,8 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
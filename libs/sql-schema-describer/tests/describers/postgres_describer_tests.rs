@@ -104,6 +104,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -124,6 +126,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -144,6 +148,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -164,6 +170,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -184,6 +192,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -204,6 +214,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -224,6 +236,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -246,6 +260,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -266,6 +282,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -293,6 +311,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -315,6 +335,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -337,6 +359,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -357,6 +381,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -377,6 +403,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -395,6 +423,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -417,6 +447,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -435,6 +467,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -455,6 +489,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -475,6 +511,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -495,6 +533,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -515,6 +555,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -533,6 +575,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -551,6 +595,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -571,6 +617,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -589,6 +637,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -607,6 +657,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -625,6 +677,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -645,6 +699,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -672,6 +728,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -699,6 +757,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -726,6 +786,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -746,6 +808,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -768,6 +832,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -790,6 +856,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -812,6 +880,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -834,6 +904,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -856,6 +928,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -874,6 +948,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -892,6 +968,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -910,6 +988,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -930,6 +1010,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -950,6 +1032,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -970,6 +1054,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -1285,6 +1371,8 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1314,6 +1402,8 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1343,6 +1433,8 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -1372,6 +1464,8 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -1461,6 +1555,8 @@ fn seemingly_escaped_backslashes_in_string_literals_must_not_be_unescaped(api: T
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -1715,6 +1811,98 @@ fn int_expressions_in_defaults(api: TestApi) {
     assert!(value.is_db_generated());
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn generated_columns_are_described(api: TestApi) {
+    let schema = r#"
+        CREATE TABLE "box" (
+            id INT PRIMARY KEY,
+            width INT8 NOT NULL,
+            height INT8 NOT NULL,
+            area INT8 GENERATED ALWAYS AS (width * height) STORED
+        );
+    "#;
+
+    api.raw_cmd(schema);
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    assert!(!table.column("width").unwrap().is_generated());
+    assert!(table.column("area").unwrap().is_generated());
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn check_constraints_are_described(api: TestApi) {
+    let schema = r#"
+        CREATE TABLE "products" (
+            id INT PRIMARY KEY,
+            price INT NOT NULL CONSTRAINT "products_price_check" CHECK (price > 0)
+        );
+    "#;
+
+    api.raw_cmd(schema);
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let ext = extract_ext(&schema);
+
+    assert_eq!(ext.check_constraints, vec![(table.id, "products_price_check".to_owned())]);
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn composite_types_are_described_as_unsupported(api: TestApi) {
+    let schema = r#"
+        CREATE TYPE address AS (street text, city text);
+
+        CREATE TABLE "company" (
+            id INT PRIMARY KEY,
+            headquarters address
+        );
+    "#;
+
+    api.raw_cmd(schema);
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let column = table.column("headquarters").unwrap();
+
+    assert_eq!(column.column_type_family(), &ColumnTypeFamily::Unsupported("address".to_owned()));
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn network_types_are_mapped_to_string_with_native_types(api: TestApi) {
+    let schema = r#"
+        CREATE TABLE "device" (
+            id INT PRIMARY KEY,
+            address inet NOT NULL,
+            subnet cidr NOT NULL,
+            mac macaddr NOT NULL
+        );
+    "#;
+
+    api.raw_cmd(schema);
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    let address = table.column("address").unwrap();
+    assert_eq!(address.column_type_family(), &ColumnTypeFamily::String);
+    assert_eq!(
+        address.column_native_type::<native_types::PostgresType>(),
+        Some(native_types::PostgresType::Inet)
+    );
+
+    let subnet = table.column("subnet").unwrap();
+    assert_eq!(subnet.column_type_family(), &ColumnTypeFamily::String);
+    assert_eq!(
+        subnet.column_native_type::<native_types::PostgresType>(),
+        Some(native_types::PostgresType::Cidr)
+    );
+
+    let mac = table.column("mac").unwrap();
+    assert_eq!(mac.column_type_family(), &ColumnTypeFamily::String);
+    assert_eq!(
+        mac.column_native_type::<native_types::PostgresType>(),
+        Some(native_types::PostgresType::MacAddr)
+    );
+}
+
 fn extract_ext(schema: &SqlSchema) -> &PostgresSchemaExt {
     schema.downcast_connector_data()
 }
@@ -261,6 +261,8 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -281,6 +283,8 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -301,6 +305,8 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -87,6 +87,8 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -103,6 +105,8 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -119,6 +123,8 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -135,6 +141,8 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -151,6 +159,8 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -167,6 +177,8 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -304,6 +316,8 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -329,6 +343,8 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -391,6 +407,8 @@ fn backslashes_in_string_literals(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
@@ -462,6 +480,8 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -478,6 +498,8 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -494,6 +516,8 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -510,6 +534,8 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
                 (
@@ -526,6 +552,8 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: false,
+                        auto_updates_to_now: false,
                     },
                 ),
             ],
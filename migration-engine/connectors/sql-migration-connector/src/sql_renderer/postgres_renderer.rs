@@ -502,6 +502,8 @@ fn render_column_type_postgres(col: ColumnWalker<'_>) -> Cow<'static, str> {
         PostgresType::Citext => "CITEXT".into(),
         PostgresType::Oid => "OID".into(),
         PostgresType::Inet => "INET".into(),
+        PostgresType::Cidr => "CIDR".into(),
+        PostgresType::MacAddr => "MACADDR".into(),
         PostgresType::Money => "MONEY".into(),
         PostgresType::SmallInt if is_autoincrement => "SMALLSERIAL".into(),
         PostgresType::SmallInt => "SMALLINT".into(),
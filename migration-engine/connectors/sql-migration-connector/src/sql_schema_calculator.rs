@@ -248,6 +248,8 @@ fn push_relation_tables(ctx: &mut Context<'_>) {
                 tpe: column_a_type,
                 default: None,
                 auto_increment: false,
+                generated: false,
+                auto_updates_to_now: false,
             },
         );
         let column_b_id = ctx.schema.describer_schema.push_column(
@@ -257,6 +259,8 @@ fn push_relation_tables(ctx: &mut Context<'_>) {
                 tpe: column_b_type,
                 default: None,
                 auto_increment: false,
+                generated: false,
+                auto_updates_to_now: false,
             },
         );
 
@@ -396,6 +400,8 @@ fn push_column_for_model_enum_scalar_field(
             tpe: ctx.flavour.enum_column_type(field, r#enum.database_name()),
             default,
             auto_increment: false,
+            generated: false,
+            auto_updates_to_now: false,
         },
     );
 }
@@ -425,6 +431,8 @@ fn push_column_for_model_unsupported_scalar_field(
                 }
             }),
             auto_increment: false,
+            generated: false,
+            auto_updates_to_now: false,
         },
     );
 }
@@ -513,6 +521,8 @@ fn push_column_for_builtin_scalar_type(
                 native_type: Some(native_type),
             },
             auto_increment: field.is_autoincrement() || ctx.flavour.field_is_implicit_autoincrement_primary_key(field),
+            generated: false,
+            auto_updates_to_now: false,
         },
     );
 
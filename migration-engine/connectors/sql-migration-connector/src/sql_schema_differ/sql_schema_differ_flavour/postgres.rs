@@ -324,6 +324,14 @@ fn postgres_native_type_change_riskyness(previous: PostgresType, next: PostgresT
                 PostgresType::Citext | PostgresType::Text | PostgresType::VarChar(_) => ColumnTypeChange::SafeCast,
                 _ => NotCastable,
             },
+            PostgresType::Cidr => match next {
+                PostgresType::Citext | PostgresType::Text | PostgresType::VarChar(_) => ColumnTypeChange::SafeCast,
+                _ => NotCastable,
+            },
+            PostgresType::MacAddr => match next {
+                PostgresType::Citext | PostgresType::Text | PostgresType::VarChar(_) => ColumnTypeChange::SafeCast,
+                _ => NotCastable,
+            },
             PostgresType::Money => match next {
                 PostgresType::Citext | PostgresType::Text | PostgresType::VarChar(_) => ColumnTypeChange::SafeCast,
                 PostgresType::Decimal(_) => ColumnTypeChange::RiskyCast,
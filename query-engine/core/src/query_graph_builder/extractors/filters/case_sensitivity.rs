@@ -0,0 +1,113 @@
+use connector::{CompositeCondition, Filter, QueryMode};
+
+/// Applies a query-level case-insensitivity default to every scalar filter in the tree whose
+/// `mode` is still `QueryMode::Default`. An explicit per-field `mode: default` parses to that same
+/// value (see `extract_scalar_filters`), so it is promoted as well; only filters that already
+/// carry `mode: insensitive` are left as they are.
+pub fn apply_default_insensitive_mode(filter: Filter) -> Filter {
+    match filter {
+        Filter::And(filters) => Filter::And(filters.into_iter().map(apply_default_insensitive_mode).collect()),
+        Filter::Or(filters) => Filter::Or(filters.into_iter().map(apply_default_insensitive_mode).collect()),
+        Filter::Not(filters) => Filter::Not(filters.into_iter().map(apply_default_insensitive_mode).collect()),
+
+        Filter::Scalar(mut sf) => {
+            if sf.mode == QueryMode::Default {
+                sf.mode = QueryMode::Insensitive;
+            }
+
+            Filter::Scalar(sf)
+        }
+
+        Filter::Relation(mut rf) => {
+            rf.nested_filter = Box::new(apply_default_insensitive_mode(*rf.nested_filter));
+            Filter::Relation(rf)
+        }
+
+        Filter::Composite(mut cf) => {
+            cf.condition = Box::new(match *cf.condition {
+                CompositeCondition::Every(f) => CompositeCondition::Every(apply_default_insensitive_mode(f)),
+                CompositeCondition::Some(f) => CompositeCondition::Some(apply_default_insensitive_mode(f)),
+                CompositeCondition::None(f) => CompositeCondition::None(apply_default_insensitive_mode(f)),
+                CompositeCondition::Is(f) => CompositeCondition::Is(apply_default_insensitive_mode(f)),
+                CompositeCondition::IsNot(f) => CompositeCondition::IsNot(apply_default_insensitive_mode(f)),
+                other => other,
+            });
+
+            Filter::Composite(cf)
+        }
+
+        Filter::Aggregation(af) => Filter::Aggregation(match af {
+            connector::AggregationFilter::Count(f) => {
+                connector::AggregationFilter::Count(Box::new(apply_default_insensitive_mode(*f)))
+            }
+            connector::AggregationFilter::Average(f) => {
+                connector::AggregationFilter::Average(Box::new(apply_default_insensitive_mode(*f)))
+            }
+            connector::AggregationFilter::Sum(f) => {
+                connector::AggregationFilter::Sum(Box::new(apply_default_insensitive_mode(*f)))
+            }
+            connector::AggregationFilter::Min(f) => {
+                connector::AggregationFilter::Min(Box::new(apply_default_insensitive_mode(*f)))
+            }
+            connector::AggregationFilter::Max(f) => {
+                connector::AggregationFilter::Max(Box::new(apply_default_insensitive_mode(*f)))
+            }
+        }),
+
+        f => f,
+    }
+}
+
+#[test]
+fn ensure_global_insensitive_mode_applies_to_nested_filters() {
+    use connector::{RelationCompare, ScalarCompare};
+    use prisma_models::{InternalDataModelBuilder, PrismaValue};
+
+    let internal_dm = InternalDataModelBuilder::new(
+        r#"
+        model Post {
+            id       Int    @id
+            title    String
+            authorId Int?
+            author   Author? @relation(fields: [authorId], references: [id])
+        }
+
+        model Author {
+            id   Int    @id
+            name String
+            posts Post[]
+        }
+        "#,
+    )
+    .build("not_important".to_owned());
+
+    let post_model = internal_dm.find_model("Post").unwrap();
+    let author_model = internal_dm.find_model("Author").unwrap();
+
+    let title = post_model.fields().find_from_scalar("title").unwrap();
+    let name = author_model.fields().find_from_scalar("name").unwrap();
+    let author_field = post_model.fields().find_from_relation_fields("author").unwrap();
+
+    let nested = author_field.to_one_related(name.equals(PrismaValue::String("Bob".to_owned())));
+
+    let filter = Filter::And(vec![title.equals(PrismaValue::String("Cats".to_owned())), nested]);
+    let result = apply_default_insensitive_mode(filter);
+
+    match result {
+        Filter::And(filters) => {
+            match &filters[0] {
+                Filter::Scalar(sf) => assert_eq!(sf.mode, QueryMode::Insensitive),
+                other => panic!("Expected a scalar filter, got: {:?}", other),
+            }
+
+            match &filters[1] {
+                Filter::Relation(rf) => match rf.nested_filter.as_ref() {
+                    Filter::Scalar(sf) => assert_eq!(sf.mode, QueryMode::Insensitive),
+                    other => panic!("Expected a scalar filter, got: {:?}", other),
+                },
+                other => panic!("Expected a relation filter, got: {:?}", other),
+            }
+        }
+        other => panic!("Expected an And filter, got: {:?}", other),
+    }
+}
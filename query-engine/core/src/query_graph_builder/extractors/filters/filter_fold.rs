@@ -98,6 +98,33 @@ fn ensure_or_folded() {
     assert_eq!(input, expected_output)
 }
 
+// A single-element `AND`/`OR` carries no boolean-logic information, but still costs the
+// connectors an unnecessary wrapping clause in the generated SQL. Collapsing it to the
+// inner filter keeps the query graph (and the SQL built from it) as flat as possible.
+#[test]
+fn ensure_single_element_and_is_collapsed_to_the_inner_filter() {
+    use connector::ScalarCompare;
+    use prisma_models::{InternalDataModelBuilder, PrismaValue};
+
+    let internal_dm = InternalDataModelBuilder::new(
+        r#"
+        model User {
+            id   Int @id
+            age  Int
+        }
+        "#,
+    )
+    .build("not_important".to_owned());
+
+    let model = internal_dm.find_model("User").unwrap();
+    let field = model.fields().find_from_scalar("age").unwrap();
+    let equals = field.equals(PrismaValue::Int(1));
+
+    let input = fold_filter(Filter::And(vec![equals.clone()]));
+
+    assert_eq!(input, equals);
+}
+
 #[test]
 fn ensure_not_is_not_folded() {
     let input = fold_filter(Filter::Not(vec![Filter::Empty, Filter::Not(vec![Filter::Empty])]));
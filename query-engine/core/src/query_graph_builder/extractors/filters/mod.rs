@@ -1,3 +1,4 @@
+mod case_sensitivity;
 mod composite;
 mod filter_fold;
 mod filter_grouping;
@@ -12,6 +13,7 @@ use crate::{
 use connector::{
     filter::Filter, CompositeCompare, QueryMode, RelationCompare, ScalarCompare, ScalarCondition, ScalarProjection,
 };
+pub(crate) use case_sensitivity::apply_default_insensitive_mode;
 use filter_fold::*;
 use filter_grouping::*;
 use prisma_models::{
@@ -22,12 +24,18 @@ use std::{collections::HashMap, convert::TryInto, str::FromStr};
 
 /// Extracts a filter for a unique selector, i.e. a filter that selects exactly one record.
 pub fn extract_unique_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraphBuilderResult<Filter> {
+    if value_map.len() > 1 {
+        validate_not_over_specified(&value_map, model)?;
+    }
+
     let filters = value_map
         .into_iter()
         .map(|(field_name, value): (String, ParsedInputValue)| {
             // Always try to resolve regular fields first. If that fails, try to resolve compound fields.
             match model.fields().find_from_scalar(&field_name) {
                 Ok(field) => {
+                    ensure_field_is_unique(&field, model)?;
+
                     let value: PrismaValue = value.try_into()?;
                     Ok(field.equals(value))
                 }
@@ -46,6 +54,61 @@ pub fn extract_unique_filter(value_map: ParsedInputMap, model: &ModelRef) -> Que
     Ok(Filter::and(filters))
 }
 
+/// Guards against building a unique filter on a field that isn't actually declared unique.
+/// A resolved `find_from_scalar` only checks that the field exists, not that it's safe to use
+/// as a record selector, so a field that's merely part of a `@@unique` would otherwise slip
+/// through and produce a finder that can match more than one record. Being the sole member of a
+/// single-column `@@unique([field])` counts; being one of several members of a compound
+/// `@@unique` does not, since the compound as a whole (not any one of its fields) is what's unique.
+fn ensure_field_is_unique(field: &ScalarFieldRef, model: &ModelRef) -> QueryGraphBuilderResult<()> {
+    let is_unique = field.is_id()
+        || field.unique()
+        || model
+            .unique_indexes()
+            .into_iter()
+            .any(|index| index.fields().len() == 1 && index.fields()[0].name == field.name);
+
+    if is_unique {
+        Ok(())
+    } else {
+        Err(QueryGraphBuilderError::InputError(format!(
+            "Field {} is not a valid selector for model {} because it is neither a declared id nor unique on its own.",
+            field.name, model.name
+        )))
+    }
+}
+
+/// Compound uniques are always addressed through a single synthesized field name (e.g.
+/// `id_email`), so a unique `where` with more than one top-level key is never valid, regardless
+/// of whether the individual keys themselves happen to name unique fields. This distinguishes the
+/// two ways that can go wrong, which otherwise both surfaced as the same generic resolution error.
+fn validate_not_over_specified(value_map: &ParsedInputMap, model: &ModelRef) -> QueryGraphBuilderResult<()> {
+    let field_names: Vec<&str> = value_map.keys().map(String::as_str).collect();
+
+    let all_individually_unique = field_names.iter().all(|field_name| {
+        model
+            .fields()
+            .find_from_scalar(field_name)
+            .map(|field| ensure_field_is_unique(&field, model).is_ok())
+            .unwrap_or(false)
+    });
+
+    if all_individually_unique {
+        Err(QueryGraphBuilderError::InputError(format!(
+            "Ambiguous unique selector on model {}: expected exactly one unique field, but got {} ({}).",
+            model.name,
+            field_names.len(),
+            field_names.join(", ")
+        )))
+    } else {
+        Err(QueryGraphBuilderError::InputError(format!(
+            "Unknown compound unique selector on model {}: ({}) is not a declared id or unique combination.",
+            model.name,
+            field_names.join(", ")
+        )))
+    }
+}
+
 fn handle_compound_field(fields: Vec<ScalarFieldRef>, value: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
     let mut input_map: ParsedInputMap = value.try_into()?;
 
@@ -72,100 +135,123 @@ fn handle_compound_field(fields: Vec<ScalarFieldRef>, value: ParsedInputValue) -
 /// | OR   | return empty list | validate single filter | validate all filters |
 /// | AND  | return all items  | validate single filter | validate all filters |
 /// | NOT  | return all items  | validate single filter | validate all filters |
+///
+/// `AND`/`OR`/`NOT` groups and relation filters (`some`, `every`, `none`, `is`, `isNot`) nest
+/// recursively; [`DEFAULT_MAX_FILTER_DEPTH`] bounds how deep they're allowed to go. Use
+/// [`extract_filter_with_max_depth`] to apply a different limit.
 pub fn extract_filter<T>(value_map: ParsedInputMap, container: T) -> QueryGraphBuilderResult<Filter>
+where
+    T: Into<ParentContainer>,
+{
+    extract_filter_with_max_depth(value_map, container, DEFAULT_MAX_FILTER_DEPTH)
+}
+
+/// A conservative ceiling on relation/group nesting depth for [`extract_filter`]. Pathologically
+/// deep input would otherwise translate into equally deep SQL (subqueries/joins); legitimate
+/// filters should never come close to this limit.
+pub const DEFAULT_MAX_FILTER_DEPTH: usize = 1_000;
+
+/// Same as [`extract_filter`], but with a caller-supplied nesting limit instead of
+/// [`DEFAULT_MAX_FILTER_DEPTH`].
+pub fn extract_filter_with_max_depth<T>(
+    value_map: ParsedInputMap,
+    container: T,
+    max_depth: usize,
+) -> QueryGraphBuilderResult<Filter>
 where
     T: Into<ParentContainer>,
 {
     let container = container.into();
+    let filter = extract_filter_rec(value_map, &container, 0, max_depth)?;
 
-    // We define an internal function so we can track the recursion depth. Empty
-    // filters at the root layer cannot always be removed.
-    fn extract_filter(
-        value_map: ParsedInputMap,
-        container: &ParentContainer,
-        depth: usize,
-    ) -> QueryGraphBuilderResult<Filter> {
-        let filters = value_map
-            .into_iter()
-            .map(|(key, value)| {
-                // 2 possibilities: Either a filter group (and, or, not) with a vector/object, or a field name with a filter object behind.
-                match FilterGrouping::from_str(&key) {
-                    Ok(filter_kind) => {
-                        let filters = match value {
-                            ParsedInputValue::List(values) => values
-                                .into_iter()
-                                .map(|val| extract_filter(val.try_into()?, container, depth + 1))
-                                .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?,
-
-                            // Single map to vec coercion
-                            ParsedInputValue::Map(map) => {
-                                extract_filter(map, container, depth + 1).map(|res| vec![res])?
-                            }
-
-                            _ => unreachable!(),
-                        };
-
-                        // strip empty filters
-                        let filters = filters
-                            .into_iter()
-                            .filter(|filter| !matches!(filter, Filter::Empty))
-                            .collect::<Vec<Filter>>();
-
-                        match filters.len() {
-                            0 => match depth {
-                                0 => match filter_kind {
-                                    FilterGrouping::And => Ok(Filter::and(filters)),
-                                    FilterGrouping::Or => Ok(Filter::or(filters)),
-                                    FilterGrouping::Not => Ok(Filter::not(filters)),
-                                },
-                                _ => Ok(Filter::empty()),
-                            },
-                            1 => match filter_kind {
-                                FilterGrouping::Not => Ok(Filter::not(filters)),
-                                _ => Ok(filters.into_iter().next().unwrap()),
-                            },
-                            _ => match filter_kind {
-                                FilterGrouping::And => Ok(Filter::and(filters)),
-                                FilterGrouping::Or => Ok(Filter::or(filters)),
-                                FilterGrouping::Not => Ok(Filter::not(filters)),
-                            },
-                        }
-                    }
-                    Err(_) => {
-                        let filters = match container.find_field(&key).expect("Invalid field passed validation.") {
-                            Field::Relation(rf) => extract_relation_filters(&rf, value),
-                            Field::Scalar(sf) => extract_scalar_filters(&sf, value),
-                            Field::Composite(cf) => extract_composite_filters(&cf, value),
-                        }?;
-
-                        // strip empty filters
-                        let filters = filters
+    Ok(merge_search_filters(filter))
+}
+
+pub(crate) fn extract_filter_rec(
+    value_map: ParsedInputMap,
+    container: &ParentContainer,
+    depth: usize,
+    max_depth: usize,
+) -> QueryGraphBuilderResult<Filter> {
+    if depth > max_depth {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "Query filter nesting depth exceeded the maximum allowed depth of {}.",
+            max_depth
+        )));
+    }
+
+    let filters = value_map
+        .into_iter()
+        .map(|(key, value)| {
+            // 2 possibilities: Either a filter group (and, or, not) with a vector/object, or a field name with a filter object behind.
+            match FilterGrouping::from_str(&key) {
+                Ok(filter_kind) => {
+                    let filters = match value {
+                        ParsedInputValue::List(values) => values
                             .into_iter()
-                            .filter(|filter| !matches!(filter, Filter::Empty))
-                            .collect::<Vec<Filter>>();
+                            .map(|val| extract_filter_rec(val.try_into()?, container, depth + 1, max_depth))
+                            .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?,
 
-                        match filters.len() {
-                            0 => Ok(Filter::empty()),
-                            1 => Ok(filters.into_iter().next().unwrap()),
-                            _ => Ok(Filter::and(filters)),
+                        // Single map to vec coercion
+                        ParsedInputValue::Map(map) => {
+                            extract_filter_rec(map, container, depth + 1, max_depth).map(|res| vec![res])?
                         }
+
+                        _ => unreachable!(),
+                    };
+
+                    // strip empty filters
+                    let filters = filters
+                        .into_iter()
+                        .filter(|filter| !matches!(filter, Filter::Empty))
+                        .collect::<Vec<Filter>>();
+
+                    // An empty AND/OR/NOT is a meaningful condition, not a no-op: per SQL logic, an
+                    // empty `AND` is a tautology (matches everything) and an empty `OR` is a
+                    // contradiction (matches nothing). We must keep producing `Filter::And`/`Filter::Or`
+                    // (as opposed to collapsing to `Filter::Empty`, which the surrounding list would
+                    // then silently drop) so that meaning survives however deeply the group is nested.
+                    match filters.len() {
+                        1 => match filter_kind {
+                            FilterGrouping::Not => Ok(Filter::not(filters)),
+                            _ => Ok(filters.into_iter().next().unwrap()),
+                        },
+                        _ => match filter_kind {
+                            FilterGrouping::And => Ok(Filter::and(filters)),
+                            FilterGrouping::Or => Ok(Filter::or(filters)),
+                            FilterGrouping::Not => Ok(Filter::not(filters)),
+                        },
                     }
                 }
-            })
-            .filter(|filter| !matches!(filter, Ok(Filter::Empty)))
-            .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?;
-
-        match filters.len() {
-            0 => Ok(Filter::empty()),
-            1 => Ok(filters.into_iter().next().unwrap()),
-            _ => Ok(Filter::and(filters)),
-        }
-    }
-
-    let filter = extract_filter(value_map, &container, 0)?;
-    let filter = merge_search_filters(filter);
+                Err(_) => {
+                    let filters = match container.find_field(&key).expect("Invalid field passed validation.") {
+                        Field::Relation(rf) => extract_relation_filters(&rf, value, depth, max_depth),
+                        Field::Scalar(sf) => extract_scalar_filters(&sf, value),
+                        Field::Composite(cf) => extract_composite_filters(&cf, value),
+                    }?;
+
+                    // strip empty filters
+                    let filters = filters
+                        .into_iter()
+                        .filter(|filter| !matches!(filter, Filter::Empty))
+                        .collect::<Vec<Filter>>();
+
+                    match filters.len() {
+                        0 => Ok(Filter::empty()),
+                        1 => Ok(filters.into_iter().next().unwrap()),
+                        _ => Ok(Filter::and(filters)),
+                    }
+                }
+            }
+        })
+        .filter(|filter| !matches!(filter, Ok(Filter::Empty)))
+        .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?;
 
-    Ok(filter)
+    match filters.len() {
+        0 => Ok(Filter::empty()),
+        1 => Ok(filters.into_iter().next().unwrap()),
+        _ => Ok(Filter::and(filters)),
+    }
 }
 
 /// Search filters that have the same query and that are in the same condition block
@@ -273,7 +359,12 @@ fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> Qu
 
 /// Field is the field the filter is refering to and `value` is the passed filter. E.g. `where: { <field>: <value> }.
 /// `value` can be either a filter object (for shorthand filter notation) or an object (full filter syntax).
-fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
+fn extract_relation_filters(
+    field: &RelationFieldRef,
+    value: ParsedInputValue,
+    depth: usize,
+    max_depth: usize,
+) -> QueryGraphBuilderResult<Vec<Filter>> {
     match value {
         // Implicit is null filter (`where: { <field>: null }`)
         ParsedInputValue::Single(PrismaValue::Null) => Ok(vec![field.one_relation_is_null()]),
@@ -282,12 +373,13 @@ fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -
         ParsedInputValue::Map(filter_map) if filter_map.is_relation_envelope() => filter_map
             .clone()
             .into_iter()
-            .map(|(k, v)| relation::parse(&k, field, v))
+            .map(|(k, v)| relation::parse(&k, field, v, depth + 1, max_depth))
             .collect::<QueryGraphBuilderResult<Vec<_>>>(),
 
         // Implicit is
         ParsedInputValue::Map(filter_map) => {
-            extract_filter(filter_map, &field.related_model()).map(|filter| vec![field.to_one_related(filter)])
+            extract_filter_rec(filter_map, &field.related_model().into(), depth + 1, max_depth)
+                .map(|filter| vec![field.to_one_related(filter)])
         }
 
         x => Err(QueryGraphBuilderError::InputError(format!(
@@ -297,7 +389,7 @@ fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -
     }
 }
 
-fn parse_query_mode(input: ParsedInputValue) -> QueryGraphBuilderResult<QueryMode> {
+pub(crate) fn parse_query_mode(input: ParsedInputValue) -> QueryGraphBuilderResult<QueryMode> {
     let value: PrismaValue = input.try_into()?;
     let s = match value {
         PrismaValue::Enum(s) => s,
@@ -328,3 +420,581 @@ fn extract_composite_filters(
         ))),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use connector::RelationCondition;
+    use prisma_models::InternalDataModelBuilder;
+
+    fn build_model(datamodel: &str, model_name: &str) -> ModelRef {
+        let internal_dm = InternalDataModelBuilder::new(datamodel).build("not_important".to_owned());
+        internal_dm.find_model(model_name).expect("model must exist in test datamodel")
+    }
+
+    #[test]
+    fn extract_unique_filter_rejects_non_unique_field() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![("email".to_owned(), ParsedInputValue::Single(PrismaValue::String("a@b.com".to_owned())))]
+            .into_iter()
+            .collect();
+
+        let result = extract_unique_filter(value_map, &model);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for a non-unique selector field, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn extract_unique_filter_accepts_id_field() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![("id".to_owned(), ParsedInputValue::Single(PrismaValue::Int(1)))]
+            .into_iter()
+            .collect();
+
+        assert!(extract_unique_filter(value_map, &model).is_ok());
+    }
+
+    #[test]
+    fn extract_unique_filter_rejects_two_individually_unique_fields_as_ambiguous() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String @unique
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![
+            ("id".to_owned(), ParsedInputValue::Single(PrismaValue::Int(1))),
+            (
+                "email".to_owned(),
+                ParsedInputValue::Single(PrismaValue::String("a@b.com".to_owned())),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        match extract_unique_filter(value_map, &model) {
+            Err(QueryGraphBuilderError::InputError(msg)) => assert!(msg.contains("Ambiguous")),
+            other => panic!("Expected an ambiguous-selector InputError, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn extract_unique_filter_rejects_a_lone_member_of_a_compound_unique() {
+        let model = build_model(
+            r#"
+            model User {
+                id        Int    @id
+                firstName String
+                lastName  String
+
+                @@unique([firstName, lastName])
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![(
+            "firstName".to_owned(),
+            ParsedInputValue::Single(PrismaValue::String("Ada".to_owned())),
+        )]
+        .into_iter()
+        .collect();
+
+        match extract_unique_filter(value_map, &model) {
+            Err(QueryGraphBuilderError::InputError(msg)) => assert!(msg.contains("not a valid selector")),
+            other => panic!(
+                "Expected an InputError for a selector on a single member of a compound unique, got: {:?}",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn extract_unique_filter_rejects_an_unrecognized_field_combination() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+                name  String
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![
+            (
+                "email".to_owned(),
+                ParsedInputValue::Single(PrismaValue::String("a@b.com".to_owned())),
+            ),
+            (
+                "name".to_owned(),
+                ParsedInputValue::Single(PrismaValue::String("Alice".to_owned())),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        match extract_unique_filter(value_map, &model) {
+            Err(QueryGraphBuilderError::InputError(msg)) => assert!(msg.contains("Unknown compound")),
+            other => panic!("Expected an unknown-compound InputError, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn mode_insensitive_is_propagated_to_in_filter() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+            "#,
+            "User",
+        );
+
+        let email = model.fields().find_from_scalar("email").unwrap();
+
+        let filter_map: ParsedInputMap = vec![
+            (
+                filters::IN.to_owned(),
+                ParsedInputValue::List(vec![
+                    ParsedInputValue::Single(PrismaValue::String("Alice@test.com".to_owned())),
+                    ParsedInputValue::Single(PrismaValue::String("bOB@test.com".to_owned())),
+                ]),
+            ),
+            (
+                filters::MODE.to_owned(),
+                ParsedInputValue::Single(PrismaValue::Enum(filters::INSENSITIVE.to_owned())),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let filters = extract_scalar_filters(&email, ParsedInputValue::Map(filter_map)).unwrap();
+
+        assert_eq!(filters.len(), 1);
+
+        match &filters[0] {
+            Filter::Scalar(sf) => assert_eq!(sf.mode, QueryMode::Insensitive),
+            other => panic!("Expected a Scalar filter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn extract_filter_normalizes_empty_and_to_tautology() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![("AND".to_owned(), ParsedInputValue::List(vec![]))]
+            .into_iter()
+            .collect();
+
+        let filter = extract_filter(value_map, &model).unwrap();
+
+        assert_eq!(filter, Filter::And(vec![]));
+    }
+
+    #[test]
+    fn extract_filter_normalizes_empty_or_to_contradiction() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![("OR".to_owned(), ParsedInputValue::List(vec![]))]
+            .into_iter()
+            .collect();
+
+        let filter = extract_filter(value_map, &model).unwrap();
+
+        assert_eq!(filter, Filter::Or(vec![]));
+    }
+
+    #[test]
+    fn extract_filter_rejects_a_relation_operator_on_a_scalar_field() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![(
+            "email".to_owned(),
+            ParsedInputValue::Map(
+                vec![(
+                    filters::SOME.to_owned(),
+                    ParsedInputValue::Single(PrismaValue::String("a@b.com".to_owned())),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        )]
+        .into_iter()
+        .collect();
+
+        let result = extract_filter(value_map, &model);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for a `some` relation filter on a scalar field, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn extract_filter_preserves_nested_empty_or_contradiction() {
+        // A nested `OR: []` must keep meaning "matches nothing" even though it isn't at the
+        // root of the filter tree: `AND: [{ OR: [] }]` must not be silently dropped as a no-op.
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+            "#,
+            "User",
+        );
+
+        let nested: ParsedInputMap = vec![("OR".to_owned(), ParsedInputValue::List(vec![]))]
+            .into_iter()
+            .collect();
+
+        let value_map: ParsedInputMap = vec![(
+            "AND".to_owned(),
+            ParsedInputValue::List(vec![ParsedInputValue::Map(nested)]),
+        )]
+        .into_iter()
+        .collect();
+
+        let filter = extract_filter(value_map, &model).unwrap();
+
+        assert_eq!(filter, Filter::Or(vec![]));
+    }
+
+    #[test]
+    fn extract_filter_combines_multiple_top_level_keys_with_and() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+                age   Int
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![
+            (
+                "email".to_owned(),
+                ParsedInputValue::Single(PrismaValue::String("a@b.com".to_owned())),
+            ),
+            ("age".to_owned(), ParsedInputValue::Single(PrismaValue::Int(30))),
+            ("id".to_owned(), ParsedInputValue::Single(PrismaValue::Int(1))),
+        ]
+        .into_iter()
+        .collect();
+
+        let filter = extract_filter(value_map, &model).unwrap();
+
+        match filter {
+            Filter::And(filters) => assert_eq!(filters.len(), 3),
+            other => panic!("Expected an implicit AND of the three top-level keys, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn extract_filter_combines_relation_filters_on_different_relations_with_and() {
+        let model = build_model(
+            r#"
+            model User {
+                id      Int      @id
+                posts   Post[]
+                profile Profile?
+            }
+
+            model Post {
+                id       Int  @id
+                title    String
+                author   User @relation(fields: [authorId], references: [id])
+                authorId Int
+            }
+
+            model Profile {
+                id     Int  @id
+                bio    String
+                user   User @relation(fields: [userId], references: [id])
+                userId Int  @unique
+            }
+            "#,
+            "User",
+        );
+
+        let mut posts_filter: ParsedInputMap = vec![(
+            filters::SOME.to_owned(),
+            ParsedInputValue::Map(
+                vec![(
+                    "title".to_owned(),
+                    ParsedInputValue::Single(PrismaValue::String("Hello".to_owned())),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        )]
+        .into_iter()
+        .collect();
+        // `SOME`/`EVERY`/`NONE`/`IS`/`ISNOT` are only recognized as relation filter operators when the
+        // input map is tagged as a relation envelope by the query document parser.
+        posts_filter.set_tag(Some(schema::ObjectTag::RelationEnvelope));
+
+        let profile_filter: ParsedInputMap = vec![(
+            "bio".to_owned(),
+            ParsedInputValue::Single(PrismaValue::String("Engineer".to_owned())),
+        )]
+        .into_iter()
+        .collect();
+
+        let value_map: ParsedInputMap = vec![
+            ("posts".to_owned(), ParsedInputValue::Map(posts_filter)),
+            ("profile".to_owned(), ParsedInputValue::Map(profile_filter)),
+        ]
+        .into_iter()
+        .collect();
+
+        let filter = extract_filter(value_map, &model).unwrap();
+
+        match filter {
+            Filter::And(filters) => assert_eq!(filters.len(), 2),
+            other => panic!(
+                "Expected an implicit AND of the two relation filters, got: {:?}",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn extract_filter_supports_is_on_a_to_one_relation() {
+        let model = build_model(
+            r#"
+            model Post {
+                id       Int  @id
+                title    String
+                author   User @relation(fields: [authorId], references: [id])
+                authorId Int
+            }
+
+            model User {
+                id    Int    @id
+                name  String
+                posts Post[]
+            }
+            "#,
+            "Post",
+        );
+
+        let mut is_filter: ParsedInputMap = vec![(
+            filters::IS.to_owned(),
+            ParsedInputValue::Map(
+                vec![(
+                    "name".to_owned(),
+                    ParsedInputValue::Single(PrismaValue::String("x".to_owned())),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        )]
+        .into_iter()
+        .collect();
+        is_filter.set_tag(Some(schema::ObjectTag::RelationEnvelope));
+
+        let value_map: ParsedInputMap = vec![("author".to_owned(), ParsedInputValue::Map(is_filter))]
+            .into_iter()
+            .collect();
+
+        let filter = extract_filter(value_map, &model).unwrap();
+
+        match filter {
+            Filter::Relation(rf) => assert_eq!(rf.condition, RelationCondition::ToOneRelatedRecord),
+            other => panic!("Expected a to-one relation filter, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn extract_filter_supports_is_null_on_a_to_one_relation() {
+        let model = build_model(
+            r#"
+            model Post {
+                id       Int  @id
+                title    String
+                author   User @relation(fields: [authorId], references: [id])
+                authorId Int
+            }
+
+            model User {
+                id    Int    @id
+                name  String
+                posts Post[]
+            }
+            "#,
+            "Post",
+        );
+
+        let mut is_null: ParsedInputMap = vec![(filters::IS.to_owned(), ParsedInputValue::Single(PrismaValue::Null))]
+            .into_iter()
+            .collect();
+        is_null.set_tag(Some(schema::ObjectTag::RelationEnvelope));
+
+        let value_map: ParsedInputMap = vec![("author".to_owned(), ParsedInputValue::Map(is_null))]
+            .into_iter()
+            .collect();
+
+        let filter = extract_filter(value_map, &model).unwrap();
+
+        match filter {
+            Filter::OneRelationIsNull(_) => (),
+            other => panic!("Expected a `OneRelationIsNull` filter, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn extract_filter_passes_through_an_equals_filter_on_an_enum_field() {
+        // The Prisma enum value is resolved to its `@map`-ed database value before it ever reaches
+        // `extract_filter` (see `DatabaseEnumType::map_input_value`), so `admin_role` below stands
+        // in for what the query document parser would have already produced for `ADMIN`.
+        let model = build_model(
+            r#"
+            model User {
+                id   Int  @id
+                role Role
+            }
+
+            enum Role {
+                ADMIN @map("admin_role")
+                USER
+            }
+            "#,
+            "User",
+        );
+
+        let value_map: ParsedInputMap = vec![(
+            "role".to_owned(),
+            ParsedInputValue::Single(PrismaValue::Enum("admin_role".to_owned())),
+        )]
+        .into_iter()
+        .collect();
+
+        let filter = extract_filter(value_map, &model).unwrap();
+
+        match filter {
+            Filter::Scalar(sf) => assert_eq!(
+                sf.condition,
+                ScalarCondition::Equals(connector::ConditionValue::value(PrismaValue::Enum(
+                    "admin_role".to_owned()
+                )))
+            ),
+            other => panic!("Expected a scalar equals filter, got: {:?}", other),
+        }
+    }
+
+    /// Wraps `inner` in `depth` layers of `AND: [ <inner> ]`.
+    fn nest_in_and(depth: usize, inner: ParsedInputMap) -> ParsedInputMap {
+        (0..depth).fold(inner, |acc, _| {
+            vec![("AND".to_owned(), ParsedInputValue::List(vec![ParsedInputValue::Map(acc)]))]
+                .into_iter()
+                .collect()
+        })
+    }
+
+    #[test]
+    fn extract_filter_with_max_depth_rejects_filters_nested_past_the_limit() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+            "#,
+            "User",
+        );
+
+        let innermost: ParsedInputMap = vec![(
+            "email".to_owned(),
+            ParsedInputValue::Single(PrismaValue::String("a@b.com".to_owned())),
+        )]
+        .into_iter()
+        .collect();
+
+        let value_map = nest_in_and(5, innermost);
+        let result = extract_filter_with_max_depth(value_map, &model, 3);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for a filter nested past the max depth, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn extract_filter_with_max_depth_accepts_filters_within_the_limit() {
+        let model = build_model(
+            r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+            "#,
+            "User",
+        );
+
+        let innermost: ParsedInputMap = vec![(
+            "email".to_owned(),
+            ParsedInputValue::Single(PrismaValue::String("a@b.com".to_owned())),
+        )]
+        .into_iter()
+        .collect();
+
+        let value_map = nest_in_and(3, innermost);
+
+        assert!(extract_filter_with_max_depth(value_map, &model, 3).is_ok());
+    }
+}
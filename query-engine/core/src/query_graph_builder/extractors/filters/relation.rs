@@ -1,23 +1,49 @@
-use super::extract_filter;
+use super::extract_filter_rec;
 use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
 use connector::{Filter, RelationCompare};
-use prisma_models::RelationFieldRef;
+use prisma_models::{prelude::ParentContainer, RelationFieldRef};
 use schema_builder::constants::filters;
 use std::convert::TryInto;
 
-pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+pub fn parse(
+    filter_key: &str,
+    field: &RelationFieldRef,
+    input: ParsedInputValue,
+    depth: usize,
+    max_depth: usize,
+) -> QueryGraphBuilderResult<Filter> {
     let value: Option<ParsedInputMap> = input.try_into()?;
+    let related_model: ParentContainer = field.related_model().into();
 
     match (filter_key, value) {
         // Relation list filters
-        (filters::SOME, Some(value)) => Ok(field.at_least_one_related(extract_filter(value, &field.related_model())?)),
-        (filters::NONE, Some(value)) => Ok(field.no_related(extract_filter(value, &field.related_model())?)),
-        (filters::EVERY, Some(value)) => Ok(field.every_related(extract_filter(value, &field.related_model())?)),
+        (filters::SOME, Some(value)) => Ok(field.at_least_one_related(extract_filter_rec(
+            value,
+            &related_model,
+            depth,
+            max_depth,
+        )?)),
+        (filters::NONE, Some(value)) => {
+            Ok(field.no_related(extract_filter_rec(value, &related_model, depth, max_depth)?))
+        }
+        (filters::EVERY, Some(value)) => Ok(field.every_related(extract_filter_rec(
+            value,
+            &related_model,
+            depth,
+            max_depth,
+        )?)),
 
         // One-relation filters
-        (filters::IS, Some(value)) => Ok(field.to_one_related(extract_filter(value, &field.related_model())?)),
+        (filters::IS, Some(value)) => Ok(field.to_one_related(extract_filter_rec(
+            value,
+            &related_model,
+            depth,
+            max_depth,
+        )?)),
         (filters::IS, None) => Ok(field.one_relation_is_null()),
-        (filters::IS_NOT, Some(value)) => Ok(field.no_related(extract_filter(value, &field.related_model())?)),
+        (filters::IS_NOT, Some(value)) => {
+            Ok(field.no_related(extract_filter_rec(value, &related_model, depth, max_depth)?))
+        }
         (filters::IS_NOT, None) => Ok(Filter::not(vec![field.one_relation_is_null()])),
 
         _ => Err(QueryGraphBuilderError::InputError(format!(
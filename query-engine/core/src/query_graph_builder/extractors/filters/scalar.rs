@@ -46,11 +46,20 @@ impl<'a> ScalarFilterParser<'a> {
     }
 
     pub fn parse(&self, mut filter_map: ParsedInputMap) -> QueryGraphBuilderResult<Vec<Filter>> {
+        self.check_for_conflicting_bounds(&filter_map)?;
+
         let json_path: Option<JsonFilterPath> = match filter_map.remove(filters::PATH) {
             Some(v) => Some(parse_json_path(v)?),
             _ => None,
         };
 
+        if json_path.is_some() && self.field().type_identifier != TypeIdentifier::Json {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Field \"{}\" is not a Json field, but a `path` filter was provided. `path` filters are only valid on Json fields.",
+                self.field().name
+            )));
+        }
+
         let filters: Vec<Filter> = filter_map
             .into_iter()
             .map(|(name, value)| match self.field().type_identifier {
@@ -71,9 +80,54 @@ impl<'a> ScalarFilterParser<'a> {
         Ok(filters)
     }
 
+    /// Guards against a filter object setting both ends of the same open/closed bound on a
+    /// field, e.g. `{ gt: 1, gte: 1 }`, which is contradictory rather than a useful combination.
+    fn check_for_conflicting_bounds(&self, filter_map: &ParsedInputMap) -> QueryGraphBuilderResult<()> {
+        let conflicting_pairs = [
+            (filters::GREATER_THAN, filters::GREATER_THAN_OR_EQUAL),
+            (filters::LOWER_THAN, filters::LOWER_THAN_OR_EQUAL),
+        ];
+
+        for (a, b) in conflicting_pairs {
+            if filter_map.contains_key(a) && filter_map.contains_key(b) {
+                return Err(QueryGraphBuilderError::InputError(format!(
+                    "Field \"{}\" cannot have both a `{}` and a `{}` filter at the same time.",
+                    self.field().name,
+                    a,
+                    b
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Guards against applying a substring filter (`contains`, `startsWith`, `endsWith`, `search`)
+    /// to a field whose type isn't text-like, e.g. `Bytes` or `Int`. The generated schema normally
+    /// keeps these operators off such fields, but nothing else in this parser enforces it.
+    fn ensure_operator_is_applicable(&self, filter_name: &str) -> QueryGraphBuilderResult<()> {
+        let requires_string_like = matches!(
+            filter_name,
+            filters::CONTAINS | filters::STARTS_WITH | filters::ENDS_WITH | filters::SEARCH
+        );
+
+        if requires_string_like && !self.field().type_identifier.is_string_like() {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Field \"{}\" of type {} does not support the `{}` filter.",
+                self.field().name,
+                self.field().type_identifier,
+                filter_name
+            )));
+        }
+
+        Ok(())
+    }
+
     fn parse_scalar(&self, filter_name: &str, input: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
         let field = self.field();
 
+        self.ensure_operator_is_applicable(filter_name)?;
+
         match filter_name {
             filters::NOT_LOWERCASE => {
                 match input {
@@ -593,3 +647,352 @@ fn coerce_json_null(value: ConditionValue) -> ConditionValue {
         _ => value,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use connector::{ConditionListValue, ScalarListCondition};
+    use prisma_models::InternalDataModelBuilder;
+
+    fn tags_field() -> ScalarFieldRef {
+        let internal_dm = InternalDataModelBuilder::new(
+            r#"
+            model Post {
+                id   Int    @id
+                tags Int[]
+            }
+            "#,
+        )
+        .build("not_important".to_owned());
+
+        let model = internal_dm.find_model("Post").unwrap();
+        model.fields().find_from_scalar("tags").unwrap()
+    }
+
+    fn score_field() -> ScalarFieldRef {
+        let internal_dm = InternalDataModelBuilder::new(
+            r#"
+            model Post {
+                id    Int @id
+                score Int
+            }
+            "#,
+        )
+        .build("not_important".to_owned());
+
+        let model = internal_dm.find_model("Post").unwrap();
+        model.fields().find_from_scalar("score").unwrap()
+    }
+
+    fn title_field() -> ScalarFieldRef {
+        let internal_dm = InternalDataModelBuilder::new(
+            r#"
+            model Post {
+                id    Int    @id
+                title String
+            }
+            "#,
+        )
+        .build("not_important".to_owned());
+
+        let model = internal_dm.find_model("Post").unwrap();
+        model.fields().find_from_scalar("title").unwrap()
+    }
+
+    fn data_field() -> ScalarFieldRef {
+        let internal_dm = InternalDataModelBuilder::new(
+            r#"
+            model Post {
+                id   Int  @id
+                data Json
+            }
+            "#,
+        )
+        .build("not_important".to_owned());
+
+        let model = internal_dm.find_model("Post").unwrap();
+        model.fields().find_from_scalar("data").unwrap()
+    }
+
+    fn single_filter(field: &ScalarFieldRef, filter_name: &str, value: ParsedInputValue) -> Filter {
+        let map: ParsedInputMap = vec![(filter_name.to_owned(), value)].into_iter().collect();
+        let mut filters = ScalarFilterParser::new(field, false).parse(map).unwrap();
+
+        assert_eq!(filters.len(), 1);
+        filters.remove(0)
+    }
+
+    #[test]
+    fn has_filter_produces_contains_condition() {
+        let field = tags_field();
+        let filter = single_filter(&field, filters::HAS, ParsedInputValue::Single(PrismaValue::Int(1)));
+
+        match filter {
+            Filter::ScalarList(f) => assert_eq!(
+                f.condition,
+                ScalarListCondition::Contains(PrismaValue::Int(1).into())
+            ),
+            other => panic!("Expected a ScalarList filter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn has_some_filter_produces_contains_some_condition() {
+        let field = tags_field();
+        let value = ParsedInputValue::List(vec![
+            ParsedInputValue::Single(PrismaValue::Int(1)),
+            ParsedInputValue::Single(PrismaValue::Int(2)),
+        ]);
+        let filter = single_filter(&field, filters::HAS_SOME, value);
+
+        match filter {
+            Filter::ScalarList(f) => assert_eq!(
+                f.condition,
+                ScalarListCondition::ContainsSome(ConditionListValue::list(vec![
+                    PrismaValue::Int(1),
+                    PrismaValue::Int(2)
+                ]))
+            ),
+            other => panic!("Expected a ScalarList filter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn has_every_filter_with_empty_array_is_vacuously_true() {
+        let field = tags_field();
+        let filter = single_filter(&field, filters::HAS_EVERY, ParsedInputValue::List(vec![]));
+
+        match filter {
+            Filter::ScalarList(f) => assert_eq!(
+                f.condition,
+                ScalarListCondition::ContainsEvery(ConditionListValue::list(vec![]))
+            ),
+            other => panic!("Expected a ScalarList filter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn not_in_filter_produces_not_in_condition() {
+        let field = score_field();
+        let value = ParsedInputValue::List(vec![
+            ParsedInputValue::Single(PrismaValue::Int(1)),
+            ParsedInputValue::Single(PrismaValue::Int(2)),
+        ]);
+        let filter = single_filter(&field, filters::NOT_IN, value);
+
+        match filter {
+            Filter::Scalar(f) => assert_eq!(
+                f.condition,
+                connector::ScalarCondition::NotIn(ConditionListValue::list(vec![
+                    PrismaValue::Int(1),
+                    PrismaValue::Int(2)
+                ]))
+            ),
+            other => panic!("Expected a Scalar filter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn is_empty_filter_produces_is_empty_condition() {
+        let field = tags_field();
+        let filter = single_filter(&field, filters::IS_EMPTY, ParsedInputValue::Single(PrismaValue::Boolean(true)));
+
+        match filter {
+            Filter::ScalarList(f) => assert_eq!(f.condition, ScalarListCondition::IsEmpty(true)),
+            other => panic!("Expected a ScalarList filter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn combined_gt_and_gte_filters_are_rejected() {
+        let field = score_field();
+        let map: ParsedInputMap = vec![
+            (filters::GREATER_THAN.to_owned(), ParsedInputValue::Single(PrismaValue::Int(1))),
+            (
+                filters::GREATER_THAN_OR_EQUAL.to_owned(),
+                ParsedInputValue::Single(PrismaValue::Int(1)),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = ScalarFilterParser::new(&field, false).parse(map);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for conflicting gt/gte bounds, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn combined_lt_and_lte_filters_are_rejected() {
+        let field = score_field();
+        let map: ParsedInputMap = vec![
+            (filters::LOWER_THAN.to_owned(), ParsedInputValue::Single(PrismaValue::Int(1))),
+            (
+                filters::LOWER_THAN_OR_EQUAL.to_owned(),
+                ParsedInputValue::Single(PrismaValue::Int(1)),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = ScalarFilterParser::new(&field, false).parse(map);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for conflicting lt/lte bounds, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn a_single_bound_is_not_rejected() {
+        let field = score_field();
+        let map: ParsedInputMap = vec![(
+            filters::GREATER_THAN.to_owned(),
+            ParsedInputValue::Single(PrismaValue::Int(1)),
+        )]
+        .into_iter()
+        .collect();
+
+        let filters = ScalarFilterParser::new(&field, false).parse(map).unwrap();
+
+        assert_eq!(filters.len(), 1);
+    }
+
+    #[test]
+    fn contains_filter_on_int_field_is_rejected() {
+        let field = score_field();
+        let map: ParsedInputMap = vec![(filters::CONTAINS.to_owned(), ParsedInputValue::Single(PrismaValue::Int(1)))]
+            .into_iter()
+            .collect();
+
+        let result = ScalarFilterParser::new(&field, false).parse(map);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for a `contains` filter on an Int field, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn some_filter_on_a_scalar_field_is_rejected() {
+        let field = score_field();
+        let map: ParsedInputMap = vec![(filters::SOME.to_owned(), ParsedInputValue::Single(PrismaValue::Int(1)))]
+            .into_iter()
+            .collect();
+
+        let result = ScalarFilterParser::new(&field, false).parse(map);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for a `some` relation filter on a scalar field, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn a_lower_and_upper_bound_together_are_not_rejected() {
+        let field = score_field();
+        let map: ParsedInputMap = vec![
+            (filters::GREATER_THAN.to_owned(), ParsedInputValue::Single(PrismaValue::Int(1))),
+            (filters::LOWER_THAN.to_owned(), ParsedInputValue::Single(PrismaValue::Int(10))),
+        ]
+        .into_iter()
+        .collect();
+
+        let filters = ScalarFilterParser::new(&field, false).parse(map).unwrap();
+
+        assert_eq!(filters.len(), 2);
+    }
+
+    #[test]
+    fn path_and_equals_on_a_json_field_produces_a_json_path_filter() {
+        let field = data_field();
+        let map: ParsedInputMap = vec![
+            (
+                filters::PATH.to_owned(),
+                ParsedInputValue::Single(PrismaValue::String("$.a.b".to_owned())),
+            ),
+            (
+                filters::EQUALS.to_owned(),
+                ParsedInputValue::Single(PrismaValue::String("foo".to_owned())),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut filters = ScalarFilterParser::new(&field, false).parse(map).unwrap();
+
+        assert_eq!(filters.len(), 1);
+
+        match filters.remove(0) {
+            Filter::Scalar(f) => match f.condition {
+                connector::ScalarCondition::JsonCompare(json) => {
+                    assert_eq!(json.path, Some(JsonFilterPath::String("$.a.b".to_owned())));
+                    assert_eq!(
+                        *json.condition,
+                        connector::ScalarCondition::Equals(PrismaValue::String("foo".to_owned()).into())
+                    );
+                }
+                other => panic!("Expected a JsonCompare condition, got {:?}", other),
+            },
+            other => panic!("Expected a Scalar filter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn path_filter_on_a_non_json_field_is_rejected() {
+        let field = score_field();
+        let map: ParsedInputMap = vec![
+            (
+                filters::PATH.to_owned(),
+                ParsedInputValue::Single(PrismaValue::String("$.a".to_owned())),
+            ),
+            (filters::EQUALS.to_owned(), ParsedInputValue::Single(PrismaValue::Int(1))),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = ScalarFilterParser::new(&field, false).parse(map);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for a `path` filter on a non-Json field, got: {:?}", other),
+        }
+    }
+
+    // `search` maps to a full-text filter node (e.g. MySQL `MATCH ... AGAINST`), same as any other
+    // string operator, regardless of the underlying connector's search flavor.
+    #[test]
+    fn search_filter_on_a_string_field_produces_a_search_condition() {
+        let field = title_field();
+        let value = ParsedInputValue::Single(PrismaValue::String("cat & dog".to_owned()));
+        let filter = single_filter(&field, filters::SEARCH, value);
+
+        match filter {
+            Filter::Scalar(f) => assert_eq!(
+                f.condition,
+                connector::ScalarCondition::Search(PrismaValue::String("cat & dog".to_owned()).into(), vec![])
+            ),
+            other => panic!("Expected a Scalar filter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn search_filter_on_an_int_field_is_rejected() {
+        let field = score_field();
+        let map: ParsedInputMap = vec![(
+            filters::SEARCH.to_owned(),
+            ParsedInputValue::Single(PrismaValue::String("1".to_owned())),
+        )]
+        .into_iter()
+        .collect();
+
+        let result = ScalarFilterParser::new(&field, false).parse(map);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for a `search` filter on an Int field, got: {:?}", other),
+        }
+    }
+}
@@ -6,5 +6,6 @@ mod utils;
 pub use filters::*;
 pub use query_arguments::*;
 pub use rel_aggregations::*;
+pub use utils::{extract_and_validate_connect_or_create, ConnectOrCreateValidation};
 
 use crate::query_document::*;
@@ -3,7 +3,7 @@ use crate::{
     query_document::{ParsedArgument, ParsedInputMap},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::QueryArguments;
+use connector::{QueryArguments, QueryMode};
 use prisma_models::prelude::*;
 use schema_builder::constants::{aggregations, args, ordering};
 use std::convert::TryInto;
@@ -12,6 +12,12 @@ use std::convert::TryInto;
 /// e.g. that the query schema guarantees that required fields are present.
 /// Errors occur if conversions fail.
 pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> QueryGraphBuilderResult<QueryArguments> {
+    let global_case_mode = arguments
+        .iter()
+        .find(|arg| arg.name == args::CASE_SENSITIVITY)
+        .map(|arg| parse_query_mode(arg.value.clone()))
+        .transpose()?;
+
     let query_args = arguments.into_iter().fold(
         Ok(QueryArguments::new(model.clone())),
         |result: QueryGraphBuilderResult<QueryArguments>, arg| {
@@ -61,11 +67,22 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
         },
     )?;
 
+    let query_args = match global_case_mode {
+        Some(QueryMode::Insensitive) => QueryArguments {
+            filter: query_args.filter.map(apply_default_insensitive_mode),
+            ..query_args
+        },
+        _ => query_args,
+    };
+
     Ok(finalize_arguments(query_args, model))
 }
 
 /// Extracts order by conditions in order of appearance.
-fn extract_order_by(container: &ParentContainer, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<OrderBy>> {
+pub(crate) fn extract_order_by(
+    container: &ParentContainer,
+    value: ParsedInputValue,
+) -> QueryGraphBuilderResult<Vec<OrderBy>> {
     match value {
         ParsedInputValue::List(list) => list
             .into_iter()
@@ -117,8 +134,13 @@ fn process_order_object(
                     path.push(rf.into());
 
                     let (inner_field_name, inner_field_value) = object.into_iter().next().unwrap();
-                    let sort_aggregation = extract_sort_aggregation(inner_field_name.as_str())
-                        .expect("To-many relation orderBy must be an aggregation ordering.");
+                    let sort_aggregation = extract_sort_aggregation(inner_field_name.as_str()).ok_or_else(|| {
+                        QueryGraphBuilderError::InputError(format!(
+                            "Cannot order by field `{}`: `{}` is a to-many relation and can only be ordered by \
+                             an aggregation, e.g. `_count`.",
+                            inner_field_name, field_name
+                        ))
+                    })?;
 
                     let (sort_order, _) = extract_order_by_args(inner_field_value)?;
                     Ok(Some(OrderBy::to_many_aggregation(path, sort_order, sort_aggregation)))
@@ -153,8 +175,13 @@ fn process_order_object(
                     path.push(cf.into());
 
                     let (inner_field_name, inner_field_value) = object.into_iter().next().unwrap();
-                    let sort_aggregation = extract_sort_aggregation(inner_field_name.as_str())
-                        .expect("To-many composite orderBy must be an aggregation ordering.");
+                    let sort_aggregation = extract_sort_aggregation(inner_field_name.as_str()).ok_or_else(|| {
+                        QueryGraphBuilderError::InputError(format!(
+                            "Cannot order by field `{}`: `{}` is a to-many composite and can only be ordered by \
+                             an aggregation, e.g. `_count`.",
+                            inner_field_name, field_name
+                        ))
+                    })?;
 
                     let (sort_order, _) = extract_order_by_args(inner_field_value)?;
                     Ok(Some(OrderBy::to_many_aggregation(path, sort_order, sort_aggregation)))
@@ -330,6 +357,498 @@ fn extract_compound_cursor_field(
     Ok(pairs)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_document::{ParsedArgument, ParsedInputValue};
+    use prisma_models::InternalDataModelBuilder;
+
+    fn user_model() -> ModelRef {
+        let internal_dm = InternalDataModelBuilder::new(
+            r#"
+            model User {
+                id   Int    @id
+                name String
+            }
+            "#,
+        )
+        .build("not_important".to_owned());
+
+        internal_dm.find_model("User").unwrap()
+    }
+
+    fn post_with_author_model() -> ModelRef {
+        let internal_dm = InternalDataModelBuilder::new(
+            r#"
+            model Post {
+                id       Int     @id
+                title    String
+                authorId Int?
+                author   Author? @relation(fields: [authorId], references: [id])
+            }
+
+            model Author {
+                id   Int    @id
+                name String
+                posts Post[]
+            }
+            "#,
+        )
+        .build("not_important".to_owned());
+
+        internal_dm.find_model("Post").unwrap()
+    }
+
+    fn author_with_posts_model() -> ModelRef {
+        let internal_dm = InternalDataModelBuilder::new(
+            r#"
+            model Post {
+                id       Int     @id
+                title    String
+                authorId Int?
+                author   Author? @relation(fields: [authorId], references: [id])
+            }
+
+            model Author {
+                id   Int    @id
+                name String
+                posts Post[]
+            }
+            "#,
+        )
+        .build("not_important".to_owned());
+
+        internal_dm.find_model("Author").unwrap()
+    }
+
+    #[test]
+    fn explicit_null_where_is_treated_as_no_filter() {
+        let model = user_model();
+        let arguments = vec![ParsedArgument {
+            name: args::WHERE.to_owned(),
+            value: ParsedInputValue::Single(PrismaValue::Null),
+        }];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+        assert!(query_args.filter.is_none());
+    }
+
+    // A nested relation `include` with both a `where` and an `orderBy` produces a single
+    // `QueryArguments` carrying both, exactly like `find_related` builds for `RelatedRecordsQuery`.
+    #[test]
+    fn where_and_order_by_are_both_extracted_into_the_same_query_arguments() {
+        let model = user_model();
+        let arguments = vec![
+            ParsedArgument {
+                name: args::WHERE.to_owned(),
+                value: ParsedInputValue::Map(
+                    vec![(
+                        "name".to_owned(),
+                        ParsedInputValue::Single(PrismaValue::String("Bob".to_owned())),
+                    )]
+                    .into_iter()
+                    .collect(),
+                ),
+            },
+            ParsedArgument {
+                name: args::ORDER_BY.to_owned(),
+                value: ParsedInputValue::Map(
+                    vec![("name".to_owned(), ParsedInputValue::Single(PrismaValue::Enum(ordering::ASC.to_owned())))]
+                        .into_iter()
+                        .collect(),
+                ),
+            },
+            ParsedArgument {
+                name: args::TAKE.to_owned(),
+                value: ParsedInputValue::Single(PrismaValue::Int(5)),
+            },
+        ];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+
+        assert!(query_args.filter.is_some());
+        assert_eq!(query_args.order_by.len(), 1);
+        assert_eq!(query_args.take, Some(5));
+    }
+
+    // `last` (negative `take`) combined with `skip` is a well-defined, supported backward
+    // pagination request: skip from the front, then take the last `abs(take)` of what remains.
+    // It's used both with and without a `cursor` (see the `order_and_pagination` connector tests).
+    #[test]
+    fn last_combined_with_skip_is_extracted_as_is() {
+        let model = user_model();
+        let arguments = vec![
+            ParsedArgument {
+                name: args::TAKE.to_owned(),
+                value: ParsedInputValue::Single(PrismaValue::Int(-5)),
+            },
+            ParsedArgument {
+                name: args::SKIP.to_owned(),
+                value: ParsedInputValue::Single(PrismaValue::Int(10)),
+            },
+        ];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+
+        assert_eq!(query_args.take, Some(-5));
+        assert_eq!(query_args.skip, Some(10));
+    }
+
+    #[test]
+    fn last_without_skip_is_not_rejected() {
+        let model = user_model();
+        let arguments = vec![ParsedArgument {
+            name: args::TAKE.to_owned(),
+            value: ParsedInputValue::Single(PrismaValue::Int(-5)),
+        }];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+        assert_eq!(query_args.take, Some(-5));
+    }
+
+    // `take: 0` is a valid "return nothing" request, not an error or an unbounded query. It's also
+    // not treated as `last` (only negative values are), so it combines fine with `skip`.
+    #[test]
+    fn take_zero_is_extracted_as_is_and_not_treated_as_last() {
+        let model = user_model();
+        let arguments = vec![
+            ParsedArgument {
+                name: args::TAKE.to_owned(),
+                value: ParsedInputValue::Single(PrismaValue::Int(0)),
+            },
+            ParsedArgument {
+                name: args::SKIP.to_owned(),
+                value: ParsedInputValue::Single(PrismaValue::Int(10)),
+            },
+        ];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+        assert_eq!(query_args.take, Some(0));
+    }
+
+    #[test]
+    fn order_by_captures_the_nulls_placement() {
+        let model = user_model();
+        let arguments = vec![ParsedArgument {
+            name: args::ORDER_BY.to_owned(),
+            value: ParsedInputValue::Map(
+                vec![(
+                    "name".to_owned(),
+                    ParsedInputValue::Map(
+                        vec![
+                            (
+                                ordering::SORT.to_owned(),
+                                ParsedInputValue::Single(PrismaValue::Enum(ordering::ASC.to_owned())),
+                            ),
+                            (
+                                ordering::NULLS.to_owned(),
+                                ParsedInputValue::Single(PrismaValue::Enum(ordering::LAST.to_owned())),
+                            ),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    ),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        }];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+
+        assert_eq!(query_args.order_by.len(), 1);
+
+        match &query_args.order_by[0] {
+            OrderBy::Scalar(order) => assert_eq!(order.nulls_order, Some(NullsOrder::Last)),
+            other => panic!("Expected a scalar order-by, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn order_by_captures_a_relevance_ordering() {
+        let model = user_model();
+        let arguments = vec![ParsedArgument {
+            name: args::ORDER_BY.to_owned(),
+            value: ParsedInputValue::Map(
+                vec![(
+                    ordering::UNDERSCORE_RELEVANCE.to_owned(),
+                    ParsedInputValue::Map(
+                        vec![
+                            (
+                                ordering::FIELDS.to_owned(),
+                                ParsedInputValue::Single(PrismaValue::Enum("name".to_owned())),
+                            ),
+                            (
+                                ordering::SEARCH.to_owned(),
+                                ParsedInputValue::Single(PrismaValue::String("cat & dog".to_owned())),
+                            ),
+                            (
+                                ordering::SORT.to_owned(),
+                                ParsedInputValue::Single(PrismaValue::Enum(ordering::DESC.to_owned())),
+                            ),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    ),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        }];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+
+        assert_eq!(query_args.order_by.len(), 1);
+
+        match &query_args.order_by[0] {
+            OrderBy::Relevance(relevance) => {
+                assert_eq!(relevance.fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>(), vec!["name"]);
+                assert_eq!(relevance.search, "cat & dog");
+                assert_eq!(relevance.sort_order, SortOrder::Descending);
+            }
+            other => panic!("Expected a relevance ordering, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn order_by_without_nulls_defaults_to_no_explicit_placement() {
+        let model = user_model();
+        let arguments = vec![ParsedArgument {
+            name: args::ORDER_BY.to_owned(),
+            value: ParsedInputValue::Map(
+                vec![("name".to_owned(), ParsedInputValue::Single(PrismaValue::Enum(ordering::ASC.to_owned())))]
+                    .into_iter()
+                    .collect(),
+            ),
+        }];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+
+        match &query_args.order_by[0] {
+            OrderBy::Scalar(order) => assert_eq!(order.nulls_order, None),
+            other => panic!("Expected a scalar order-by, got {:?}", other),
+        }
+    }
+
+    // `orderBy: { author: { name: "asc" } }`, where `author` is a nullable to-one relation, must
+    // resolve to an ordering on `Author.name` with the relation hop recorded on the path.
+    #[test]
+    fn order_by_recurses_through_a_nullable_to_one_relation() {
+        let model = post_with_author_model();
+        let arguments = vec![ParsedArgument {
+            name: args::ORDER_BY.to_owned(),
+            value: ParsedInputValue::Map(
+                vec![(
+                    "author".to_owned(),
+                    ParsedInputValue::Map(
+                        vec![(
+                            "name".to_owned(),
+                            ParsedInputValue::Single(PrismaValue::Enum(ordering::ASC.to_owned())),
+                        )]
+                        .into_iter()
+                        .collect(),
+                    ),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        }];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+
+        assert_eq!(query_args.order_by.len(), 1);
+
+        match &query_args.order_by[0] {
+            OrderBy::Scalar(order) => {
+                assert_eq!(order.field.name, "name");
+                assert_eq!(order.path.len(), 1);
+
+                match &order.path[0] {
+                    OrderByHop::Relation(rf) => assert_eq!(rf.name, "author"),
+                    other => panic!("Expected a relation hop, got {:?}", other),
+                }
+            }
+            other => panic!("Expected a scalar order-by, got {:?}", other),
+        }
+    }
+
+    // `orderBy: { posts: { title: "asc" } }` orders directly by a scalar field on a to-many
+    // relation, which has no well-defined meaning (there can be many `posts` per `Author`).
+    // Only aggregation-based orderings (e.g. `_count`) are valid on a to-many relation.
+    #[test]
+    fn order_by_rejects_a_scalar_ordering_through_a_to_many_relation() {
+        let model = author_with_posts_model();
+        let arguments = vec![ParsedArgument {
+            name: args::ORDER_BY.to_owned(),
+            value: ParsedInputValue::Map(
+                vec![(
+                    "posts".to_owned(),
+                    ParsedInputValue::Map(
+                        vec![(
+                            "title".to_owned(),
+                            ParsedInputValue::Single(PrismaValue::Enum(ordering::ASC.to_owned())),
+                        )]
+                        .into_iter()
+                        .collect(),
+                    ),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        }];
+
+        let result = extract_query_args(arguments, &model);
+
+        match result {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!(
+                "Expected an InputError for ordering directly by a to-many relation field, got: {:?}",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn order_by_accepts_a_count_aggregation_through_a_to_many_relation() {
+        let model = author_with_posts_model();
+        let arguments = vec![ParsedArgument {
+            name: args::ORDER_BY.to_owned(),
+            value: ParsedInputValue::Map(
+                vec![(
+                    "posts".to_owned(),
+                    ParsedInputValue::Map(
+                        vec![(
+                            aggregations::UNDERSCORE_COUNT.to_owned(),
+                            ParsedInputValue::Single(PrismaValue::Enum(ordering::ASC.to_owned())),
+                        )]
+                        .into_iter()
+                        .collect(),
+                    ),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        }];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+
+        assert_eq!(query_args.order_by.len(), 1);
+
+        match &query_args.order_by[0] {
+            OrderBy::ToManyAggregation(order) => assert_eq!(order.sort_aggregation, SortAggregation::Count),
+            other => panic!("Expected a to-many aggregation order-by, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn global_case_sensitivity_applies_to_top_level_and_nested_string_filters() {
+        let model = post_with_author_model();
+        let arguments = vec![
+            ParsedArgument {
+                name: args::CASE_SENSITIVITY.to_owned(),
+                value: ParsedInputValue::Single(PrismaValue::Enum("insensitive".to_owned())),
+            },
+            ParsedArgument {
+                name: args::WHERE.to_owned(),
+                value: ParsedInputValue::Map(
+                    vec![
+                        (
+                            "title".to_owned(),
+                            ParsedInputValue::Single(PrismaValue::String("Hello".to_owned())),
+                        ),
+                        (
+                            "author".to_owned(),
+                            ParsedInputValue::Map(
+                                vec![(
+                                    "name".to_owned(),
+                                    ParsedInputValue::Single(PrismaValue::String("Bob".to_owned())),
+                                )]
+                                .into_iter()
+                                .collect(),
+                            ),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+            },
+        ];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+        let filter = query_args.filter.unwrap();
+
+        match filter {
+            connector::Filter::And(filters) => {
+                let title_filter = filters
+                    .iter()
+                    .find_map(|f| match f {
+                        connector::Filter::Scalar(sf) if sf.projection.as_single().unwrap().name == "title" => {
+                            Some(sf)
+                        }
+                        _ => None,
+                    })
+                    .expect("Expected a scalar filter on `title`");
+                assert_eq!(title_filter.mode, QueryMode::Insensitive);
+
+                let author_filter = filters
+                    .iter()
+                    .find_map(|f| match f {
+                        connector::Filter::Relation(rf) => Some(rf),
+                        _ => None,
+                    })
+                    .expect("Expected a relation filter on `author`");
+
+                match author_filter.nested_filter.as_ref() {
+                    connector::Filter::Scalar(sf) => assert_eq!(sf.mode, QueryMode::Insensitive),
+                    other => panic!("Expected a scalar filter on `author.name`, got {:?}", other),
+                }
+            }
+            other => panic!("Expected an And filter, got {:?}", other),
+        }
+    }
+
+    // Keyset pagination needs a stable order to page against. Rather than rejecting a `cursor`
+    // without an explicit `orderBy`, `finalize_arguments` falls back to ordering by the primary
+    // identifier, so results stay deterministic without making callers spell out the obvious case.
+    #[test]
+    fn cursor_without_order_by_gets_an_implicit_order_by_primary_key() {
+        let model = user_model();
+        let arguments = vec![ParsedArgument {
+            name: args::CURSOR.to_owned(),
+            value: ParsedInputValue::Map(
+                vec![("id".to_owned(), ParsedInputValue::Single(PrismaValue::Int(1)))]
+                    .into_iter()
+                    .collect(),
+            ),
+        }];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+
+        assert_eq!(query_args.order_by.len(), 1);
+
+        match &query_args.order_by[0] {
+            OrderBy::Scalar(order) => assert_eq!(order.field.name, "id"),
+            other => panic!("Expected a scalar order-by, got {:?}", other),
+        }
+    }
+
+    // An offset-only query (`skip` without `first`/`last`) is a valid way to page through results
+    // without bounding how many are returned.
+    #[test]
+    fn skip_without_take_is_not_rejected() {
+        let model = user_model();
+        let arguments = vec![ParsedArgument {
+            name: args::SKIP.to_owned(),
+            value: ParsedInputValue::Single(PrismaValue::Int(10)),
+        }];
+
+        let query_args = extract_query_args(arguments, &model).unwrap();
+
+        assert_eq!(query_args.skip, Some(10));
+        assert_eq!(query_args.take, None);
+    }
+}
+
 /// Runs final transformations on the QueryArguments.
 fn finalize_arguments(mut args: QueryArguments, model: &ModelRef) -> QueryArguments {
     // Check if the query requires an implicit ordering added to the arguments.
@@ -1,5 +1,11 @@
-use crate::schema_builder;
-use prisma_models::{ModelRef, ScalarFieldRef};
+use super::extract_unique_filter;
+use crate::{
+    query_document::{ParsedInputMap, ParsedInputValue},
+    schema_builder, QueryGraphBuilderError, QueryGraphBuilderResult,
+};
+use connector::Filter;
+use prisma_models::{ModelRef, PrismaValue, ScalarFieldRef};
+use std::convert::TryInto;
 
 /// Attempts to resolve a field name to a compound field.
 pub fn resolve_compound_field(name: &str, model: &ModelRef) -> Option<Vec<ScalarFieldRef>> {
@@ -22,3 +28,123 @@ pub fn resolve_index_fields(name: &str, model: &ModelRef) -> Option<Vec<ScalarFi
         .find(|index| schema_builder::compound_index_field_name(index) == name)
         .map(|index| index.fields())
 }
+
+/// The outcome of validating a nested `connectOrCreate` input (a `where` + a `create`) against a model.
+pub enum ConnectOrCreateValidation {
+    /// Both the `where` selector and the `create` payload are usable as-is.
+    Valid { filter: Filter, create: ParsedInputMap },
+    /// The `create` payload doesn't provide a value for one or more fields that are required on
+    /// the model and have no default, so a record could never actually be created from it.
+    MissingRequiredFields(Vec<String>),
+}
+
+/// Validates a nested `connectOrCreate` input: the `where` must resolve to a unique filter (the
+/// same logic the regular record finders use, see [`extract_unique_filter`]) and the `create` must
+/// provide a value for every scalar field that's required on `model` and doesn't have a default.
+pub fn extract_and_validate_connect_or_create(
+    where_map: ParsedInputMap,
+    create_map: ParsedInputMap,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<ConnectOrCreateValidation> {
+    let missing_fields = required_create_fields_missing(&create_map, model);
+
+    if !missing_fields.is_empty() {
+        return Ok(ConnectOrCreateValidation::MissingRequiredFields(missing_fields));
+    }
+
+    let filter = extract_unique_filter(where_map, model)?;
+
+    Ok(ConnectOrCreateValidation::Valid {
+        filter,
+        create: create_map,
+    })
+}
+
+/// Returns the names of the scalar fields that are required on `model`, have no default value,
+/// and are missing from `create_map`. Mirrors the optionality rules the schema builder applies to
+/// the generated `*CreateInput` types (see `CreateDataInputFieldMapper::map_scalar`).
+fn required_create_fields_missing(create_map: &ParsedInputMap, model: &ModelRef) -> Vec<String> {
+    model
+        .fields()
+        .scalar_writable()
+        .filter(|sf| sf.is_required() && sf.default_value.is_none() && !sf.is_updated_at)
+        .map(|sf| sf.name.clone())
+        .filter(|name| !create_map.contains_key(name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prisma_models::{InternalDataModelBuilder, PrismaValue};
+
+    fn tag_model() -> ModelRef {
+        let internal_dm = InternalDataModelBuilder::new(
+            r#"
+            model Tag {
+                id   Int    @id
+                name String @unique
+                slug String
+            }
+            "#,
+        )
+        .build("not_important".to_owned());
+
+        internal_dm.find_model("Tag").unwrap()
+    }
+
+    #[test]
+    fn connect_or_create_is_valid_when_where_is_unique_and_create_has_required_fields() {
+        let model = tag_model();
+        let where_map: ParsedInputMap = vec![(
+            "name".to_owned(),
+            ParsedInputValue::Single(PrismaValue::String("rust".to_owned())),
+        )]
+        .into_iter()
+        .collect();
+        let create_map: ParsedInputMap = vec![
+            (
+                "name".to_owned(),
+                ParsedInputValue::Single(PrismaValue::String("rust".to_owned())),
+            ),
+            (
+                "slug".to_owned(),
+                ParsedInputValue::Single(PrismaValue::String("rust".to_owned())),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        match extract_and_validate_connect_or_create(where_map, create_map, &model).unwrap() {
+            ConnectOrCreateValidation::Valid { .. } => (),
+            ConnectOrCreateValidation::MissingRequiredFields(fields) => {
+                panic!("Expected a valid connectOrCreate, got missing fields: {:?}", fields)
+            }
+        }
+    }
+
+    #[test]
+    fn connect_or_create_reports_missing_required_create_fields() {
+        let model = tag_model();
+        let where_map: ParsedInputMap = vec![(
+            "name".to_owned(),
+            ParsedInputValue::Single(PrismaValue::String("rust".to_owned())),
+        )]
+        .into_iter()
+        .collect();
+        // `slug` is required and has no default, but is missing from the create payload.
+        let create_map: ParsedInputMap = vec![(
+            "name".to_owned(),
+            ParsedInputValue::Single(PrismaValue::String("rust".to_owned())),
+        )]
+        .into_iter()
+        .collect();
+
+        match extract_and_validate_connect_or_create(where_map, create_map, &model).unwrap() {
+            ConnectOrCreateValidation::MissingRequiredFields(fields) => {
+                assert_eq!(fields, vec!["slug".to_owned()])
+            }
+            ConnectOrCreateValidation::Valid { .. } => panic!("Expected missing fields to be reported"),
+        }
+    }
+}
@@ -19,8 +19,7 @@ pub fn delete_record(
 ) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
-    let where_arg = field.arguments.lookup(args::WHERE).unwrap();
-    let filter = extract_unique_filter(where_arg.value.try_into()?, &model)?;
+    let filter = utils::extract_record_finder(&mut field, &model)?;
 
     // Prefetch read query for the delete
     let mut read_query = read::find_unique(field, Arc::clone(&model))?;
@@ -54,6 +54,26 @@ pub fn nested_connect_or_create(
     }
 }
 
+/// Runs the `where`/`create` pair of a nested `connectOrCreate` through [`extract_and_validate_connect_or_create`],
+/// turning a `create` payload that's missing required fields into a proper input error instead of
+/// letting it fail later with a less specific error from the record creation itself.
+fn assert_connect_or_create_is_valid(
+    where_map: ParsedInputMap,
+    create_map: ParsedInputMap,
+    parent_relation_field: &RelationFieldRef,
+    child_model: &ModelRef,
+) -> QueryGraphBuilderResult<(Filter, ParsedInputMap)> {
+    match extract_and_validate_connect_or_create(where_map, create_map, child_model)? {
+        ConnectOrCreateValidation::Valid { filter, create } => Ok((filter, create)),
+        ConnectOrCreateValidation::MissingRequiredFields(fields) => Err(QueryGraphBuilderError::InputError(format!(
+            "Expected a valid `create` argument for a nested connect or create on relation '{}': \
+             missing required field(s) {}.",
+            parent_relation_field.relation().name,
+            fields.join(", ")
+        ))),
+    }
+}
+
 /// Handles a nested connect-or-create many-to-many relation case.
 /// ```text
 ///    ┌ ─ ─ ─ ─ ─ ─ ─ ─ ┐
@@ -105,7 +125,12 @@ fn handle_many_to_many(
         let create_arg = value.remove(args::CREATE).unwrap();
         let create_map: ParsedInputMap = create_arg.try_into()?;
 
-        let filter = extract_unique_filter(where_map, &child_model)?;
+        let (filter, create_map) = assert_connect_or_create_is_valid(
+            where_map,
+            create_map,
+            parent_relation_field,
+            child_model,
+        )?;
         let read_node = graph.create_node(utils::read_ids_infallible(
             child_model.clone(),
             child_model.primary_identifier(),
@@ -192,7 +217,12 @@ fn handle_one_to_one(
     let create_arg = value.remove(args::CREATE).unwrap();
     let create_data: ParsedInputMap = create_arg.try_into()?;
 
-    let filter = extract_unique_filter(where_map, &child_model)?;
+    let (filter, create_data) = assert_connect_or_create_is_valid(
+        where_map,
+        create_data,
+        parent_relation_field,
+        child_model,
+    )?;
 
     if parent_relation_field.is_inlined_on_enclosing_model() {
         one_to_one_inlined_parent(
@@ -266,7 +296,12 @@ fn one_to_many_inlined_child(
         let create_arg = value.remove(args::CREATE).unwrap();
         let create_map: ParsedInputMap = create_arg.try_into()?;
 
-        let filter = extract_unique_filter(where_map, &child_model)?;
+        let (filter, create_map) = assert_connect_or_create_is_valid(
+            where_map,
+            create_map,
+            parent_relation_field,
+            child_model,
+        )?;
         let read_node = graph.create_node(utils::read_ids_infallible(
             child_model.clone(),
             child_link.clone(),
@@ -407,7 +442,12 @@ fn one_to_many_inlined_parent(
     let create_arg = value.remove(args::CREATE).unwrap();
     let create_map: ParsedInputMap = create_arg.try_into()?;
 
-    let filter = extract_unique_filter(where_map, &child_model)?;
+    let (filter, create_map) = assert_connect_or_create_is_valid(
+        where_map,
+        create_map,
+        parent_relation_field,
+        child_model,
+    )?;
     let read_node = graph.create_node(utils::read_ids_infallible(
         child_model.clone(),
         child_link.clone(),
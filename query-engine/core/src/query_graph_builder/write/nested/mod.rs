@@ -34,6 +34,8 @@ pub fn connect_nested_query(
 ) -> QueryGraphBuilderResult<()> {
     let child_model = parent_relation_field.related_model();
 
+    utils::validate_exclusive_nested_create_operations(&data_map)?;
+
     for (field_name, value) in data_map {
         match field_name.as_str() {
             operations::CREATE => nested_create(graph, connector_ctx,parent, &parent_relation_field, value, &child_model)?,
@@ -19,8 +19,7 @@ pub fn update_record(
     mut field: ParsedField,
 ) -> QueryGraphBuilderResult<()> {
     // "where"
-    let where_arg: ParsedInputMap = field.arguments.lookup(args::WHERE).unwrap().value.try_into()?;
-    let filter = extract_unique_filter(where_arg, &model)?;
+    let filter = utils::extract_record_finder(&mut field, &model)?;
 
     // "data"
     let data_argument = field.arguments.lookup(args::DATA).unwrap();
@@ -2,7 +2,7 @@ use super::*;
 use crate::{
     query_ast::*,
     query_graph::{Flow, Node, QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, ParsedField, ParsedInputMap,
+    ArgumentListLookup, ParsedField,
 };
 use connector::IntoFilter;
 use prisma_models::ModelRef;
@@ -60,9 +60,7 @@ pub fn upsert_record(
 ) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
-    let where_arg: ParsedInputMap = field.arguments.lookup(args::WHERE).unwrap().value.try_into()?;
-
-    let filter = extract_unique_filter(where_arg, &model)?;
+    let filter = utils::extract_record_finder(&mut field, &model)?;
     let model_id = model.primary_identifier();
 
     let create_argument = field.arguments.lookup(args::CREATE).unwrap();
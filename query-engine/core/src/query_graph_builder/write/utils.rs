@@ -1,13 +1,17 @@
 use crate::{
     query_ast::*,
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
+    query_graph_builder::extract_unique_filter,
+    ArgumentListLookup, ParsedField, ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
 use connector::{DatasourceFieldName, Filter, RecordFilter, WriteArgs, WriteOperation};
 use datamodel::dml::ReferentialAction;
 use indexmap::IndexMap;
 use prisma_models::{FieldSelection, ModelRef, PrismaValue, RelationFieldRef, SelectionResult};
 use schema::ConnectorContext;
+use schema_builder::constants::args;
+use schema_builder::constants::operations;
+use std::convert::TryInto;
 use std::sync::Arc;
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
@@ -20,6 +24,35 @@ pub fn coerce_vec(val: ParsedInputValue) -> Vec<ParsedInputValue> {
     }
 }
 
+/// Extracts the unique selector filter from a top-level write field's required `where` argument.
+///
+/// Schema validation guarantees the argument is present, but that guarantee lives in a different
+/// layer than this parser, so this returns a `QueryGraphBuilderError` instead of unwrapping and
+/// potentially panicking if that invariant is ever violated.
+pub fn extract_record_finder(field: &mut ParsedField, model: &ModelRef) -> QueryGraphBuilderResult<Filter> {
+    let where_arg = field.arguments.lookup(args::WHERE).ok_or_else(|| {
+        QueryGraphBuilderError::AssertionError("Expected a `where` argument, found none.".to_owned())
+    })?;
+
+    let where_map: ParsedInputMap = where_arg.value.try_into()?;
+
+    extract_unique_filter(where_map, model)
+}
+
+/// `create` and `connectOrCreate` both attempt to produce a new child record for the relation, so
+/// sending both on the same nested write is a contradiction rather than two operations to run in
+/// sequence (unlike e.g. `connect` and `create`, which combine cleanly for list relations).
+pub fn validate_exclusive_nested_create_operations(data_map: &ParsedInputMap) -> QueryGraphBuilderResult<()> {
+    if data_map.contains_key(operations::CREATE) && data_map.contains_key(operations::CONNECT_OR_CREATE) {
+        return Err(QueryGraphBuilderError::InputError(
+            "Invalid nested write: `create` and `connectOrCreate` cannot be used together on the same relation."
+                .to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
 pub fn node_is_create(graph: &QueryGraph, node: &NodeRef) -> bool {
     matches!(
         graph.node_content(node).unwrap(),
@@ -1028,3 +1061,111 @@ pub fn emulate_on_update_cascade(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsedArgument;
+    use prisma_models::InternalDataModelBuilder;
+
+    fn single_map(pairs: Vec<(&str, ParsedInputValue)>) -> ParsedInputMap {
+        pairs.into_iter().map(|(name, value)| (name.to_owned(), value)).collect()
+    }
+
+    fn user_model() -> ModelRef {
+        let internal_dm = InternalDataModelBuilder::new(
+            r#"
+            model User {
+                id   Int    @id
+                name String
+            }
+            "#,
+        )
+        .build("not_important".to_owned());
+
+        internal_dm.find_model("User").unwrap()
+    }
+
+    fn field_without_arguments() -> ParsedField {
+        ParsedField {
+            name: "findUniqueUser".to_owned(),
+            alias: None,
+            arguments: vec![],
+            nested_fields: None,
+        }
+    }
+
+    fn field_with_where(where_map: ParsedInputMap) -> ParsedField {
+        ParsedField {
+            name: "findUniqueUser".to_owned(),
+            alias: None,
+            arguments: vec![ParsedArgument {
+                name: args::WHERE.to_owned(),
+                value: ParsedInputValue::Map(where_map),
+            }],
+            nested_fields: None,
+        }
+    }
+
+    #[test]
+    fn extract_record_finder_without_where_does_not_panic() {
+        let model = user_model();
+        let mut field = field_without_arguments();
+
+        match extract_record_finder(&mut field, &model) {
+            Err(QueryGraphBuilderError::AssertionError(_)) => (),
+            other => panic!("Expected an AssertionError for a missing `where` argument, got: {:?}", other),
+        }
+    }
+
+    // `extract_record_finder` only builds a selector; it never looks the record up, so it cannot
+    // distinguish "found" from "not found" and must not be conflated with that concern. A `where`
+    // on a genuinely unique field always succeeds here regardless of whether a matching record
+    // exists, while a `where` on a non-unique field is rejected as invalid input.
+    #[test]
+    fn extract_record_finder_distinguishes_invalid_args_from_record_not_found() {
+        let model = user_model();
+
+        let valid_where = single_map(vec![("id", ParsedInputValue::Single(PrismaValue::Int(1)))]);
+        let mut field = field_with_where(valid_where);
+
+        match extract_record_finder(&mut field, &model) {
+            Ok(_) => (),
+            other => panic!("Expected a valid unique finder to resolve to a filter, got: {:?}", other),
+        }
+
+        let malformed_where = single_map(vec![(
+            "name",
+            ParsedInputValue::Single(PrismaValue::String("Bob".to_owned())),
+        )]);
+        let mut field = field_with_where(malformed_where);
+
+        match extract_record_finder(&mut field, &model) {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!(
+                "Expected an InputError for a non-unique selector field, not a record-not-found style error, got: {:?}",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn create_and_connect_or_create_on_the_same_relation_is_rejected() {
+        let data_map = single_map(vec![
+            (operations::CREATE, ParsedInputValue::Single(PrismaValue::Int(1))),
+            (operations::CONNECT_OR_CREATE, ParsedInputValue::Single(PrismaValue::Int(2))),
+        ]);
+
+        match validate_exclusive_nested_create_operations(&data_map) {
+            Err(QueryGraphBuilderError::InputError(_)) => (),
+            other => panic!("Expected an InputError for conflicting nested create operations, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn create_alone_is_not_rejected() {
+        let data_map = single_map(vec![(operations::CREATE, ParsedInputValue::Single(PrismaValue::Int(1)))]);
+
+        assert!(validate_exclusive_nested_create_operations(&data_map).is_ok());
+    }
+}
@@ -197,6 +197,12 @@ impl TypeIdentifier {
             TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal
         )
     }
+
+    /// Whether the type is text-like, i.e. it makes sense to apply substring filters
+    /// (`contains`, `startsWith`, `endsWith`, `search`) to it.
+    pub fn is_string_like(&self) -> bool {
+        matches!(self, TypeIdentifier::String | TypeIdentifier::UUID | TypeIdentifier::Xml)
+    }
 }
 
 impl std::fmt::Display for TypeIdentifier {
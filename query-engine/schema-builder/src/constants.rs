@@ -22,6 +22,9 @@ pub mod args {
 
     // createMany-specific args
     pub const SKIP_DUPLICATES: &str = "skipDuplicates";
+
+    // query-level default applied to every filter whose `mode` is still the default (an explicit `mode: default` included)
+    pub const CASE_SENSITIVITY: &str = "_caseSensitivity";
 }
 
 pub mod operations {
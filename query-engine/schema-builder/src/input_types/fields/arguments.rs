@@ -1,4 +1,5 @@
 use super::*;
+use crate::enum_types::query_mode_enum;
 use crate::input_types::objects::order_by_objects::OrderByOptions;
 use crate::mutations::create_one;
 use constants::args;
@@ -121,6 +122,13 @@ pub(crate) fn relation_selection_arguments(
         );
     }
 
+    // Only expose the query-level case sensitivity override for connectors that can honor it.
+    if ctx.has_capability(ConnectorCapability::InsensitiveFilters) {
+        let enum_type = query_mode_enum(ctx);
+
+        args.push(input_field(args::CASE_SENSITIVITY, InputType::enum_type(enum_type), None).optional());
+    }
+
     args
 }
 
@@ -106,6 +106,54 @@ impl DatabaseEnumType {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prisma_models::{InternalEnum, InternalEnumValue};
+
+    fn role_enum_type() -> DatabaseEnumType {
+        let internal_enum = InternalEnum::new(
+            "Role",
+            vec![
+                InternalEnumValue::new("Admin", Some("admin_role".to_owned())),
+                InternalEnumValue::new("User", None),
+            ],
+        );
+
+        DatabaseEnumType {
+            identifier: Identifier::new("Role", "model"),
+            internal_enum: std::sync::Arc::new(internal_enum),
+        }
+    }
+
+    #[test]
+    fn map_input_value_resolves_the_prisma_name_to_the_mapped_db_name() {
+        let enum_type = role_enum_type();
+
+        assert_eq!(
+            enum_type.map_input_value("Admin"),
+            Some(PrismaValue::Enum("admin_role".to_owned()))
+        );
+    }
+
+    #[test]
+    fn map_input_value_falls_back_to_the_prisma_name_when_unmapped() {
+        let enum_type = role_enum_type();
+
+        assert_eq!(
+            enum_type.map_input_value("User"),
+            Some(PrismaValue::Enum("User".to_owned()))
+        );
+    }
+
+    #[test]
+    fn map_input_value_returns_none_for_an_unknown_enum_value() {
+        let enum_type = role_enum_type();
+
+        assert_eq!(enum_type.map_input_value("Superadmin"), None);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FieldRefEnumType {
     pub identifier: Identifier,